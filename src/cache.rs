@@ -0,0 +1,418 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    fs,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// How long a cached response stays fresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ttl {
+    Forever,
+    For(Duration),
+}
+
+/// Stores raw response bodies keyed by request URL. Implementations handle
+/// expiry themselves and are free to drop entries at any time.
+pub trait Cache: Debug + Send + Sync {
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+    fn put(&self, key: &str, value: &[u8], ttl: Ttl);
+}
+
+impl<T: Cache + ?Sized> Cache for Arc<T> {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, value: &[u8], ttl: Ttl) {
+        (**self).put(key, value, ttl)
+    }
+}
+
+/// Which methods are cached and for how long, keyed by Riot's method id.
+/// Methods without a TTL always go to the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachePolicy {
+    ttls: HashMap<&'static str, Ttl>,
+}
+
+impl Default for CachePolicy {
+    /// Finished matches never change, so they are kept forever; summoners can
+    /// change name or level, so they are only kept for a few minutes.
+    fn default() -> Self {
+        let summoner = Ttl::For(Duration::from_secs(5 * 60));
+        Self::empty()
+            .ttl("match-v5.getMatch", Ttl::Forever)
+            .ttl("match-v5.getTimeline", Ttl::Forever)
+            .ttl("summoner-v4.getByAccountId", summoner)
+            .ttl("summoner-v4.getBySummonerName", summoner)
+            .ttl("summoner-v4.getByPUUID", summoner)
+            .ttl("summoner-v4.getBySummonerId", summoner)
+    }
+}
+
+impl CachePolicy {
+    pub fn empty() -> Self {
+        Self {
+            ttls: HashMap::new(),
+        }
+    }
+
+    pub fn ttl(mut self, method: &'static str, ttl: Ttl) -> Self {
+        self.ttls.insert(method, ttl);
+        self
+    }
+
+    pub fn uncached(mut self, method: &'static str) -> Self {
+        self.ttls.remove(method);
+        self
+    }
+
+    pub fn get(&self, method: &str) -> Option<Ttl> {
+        self.ttls.get(method).copied()
+    }
+}
+
+/// A snapshot of a client's cache counters. Only lookups for methods the
+/// [`CachePolicy`] caches are counted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            total => self.hits as f64 / total as f64,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CacheMetrics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheMetrics {
+    pub(crate) fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MemoryEntry {
+    value: Vec<u8>,
+    expires: Option<Instant>,
+    used: u64,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    entries: HashMap<String, MemoryEntry>,
+    /// Keys by the tick they were last used at, oldest first.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl MemoryState {
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.used);
+        }
+    }
+}
+
+/// An in-memory cache that evicts the least recently used entry once it holds
+/// `capacity` responses.
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    state: Mutex<MemoryState>,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(MemoryState::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut state = self.state.lock().unwrap();
+        let expired = match state.entries.get(key)?.expires {
+            Some(expires) => expires <= Instant::now(),
+            None => false,
+        };
+        if expired {
+            state.remove(key);
+            return None;
+        }
+
+        state.tick += 1;
+        let tick = state.tick;
+        let entry = state.entries.get_mut(key)?;
+        let used = std::mem::replace(&mut entry.used, tick);
+        let value = entry.value.clone();
+        state.order.remove(&used);
+        state.order.insert(tick, key.to_string());
+        Some(value)
+    }
+
+    fn put(&self, key: &str, value: &[u8], ttl: Ttl) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        state.remove(key);
+        while state.entries.len() >= self.capacity {
+            let Some((_, oldest)) = state.order.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+        }
+
+        state.tick += 1;
+        let used = state.tick;
+        let expires = match ttl {
+            Ttl::Forever => None,
+            Ttl::For(ttl) => Some(Instant::now() + ttl),
+        };
+        state.order.insert(used, key.to_string());
+        state.entries.insert(
+            key.to_string(),
+            MemoryEntry {
+                value: value.to_vec(),
+                expires,
+                used,
+            },
+        );
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DiskHeader {
+    key: String,
+    /// Seconds since the Unix epoch.
+    expires: Option<u64>,
+}
+
+/// A cache that keeps one file per response in `dir`, so match data survives
+/// restarts. Read and write failures are treated as misses.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.cache", fnv1a(key)))
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl Cache for DiskCache {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let path = self.path(key);
+        let contents = fs::read(&path).ok()?;
+        let split = contents.iter().position(|&byte| byte == b'\n')?;
+        let header: DiskHeader = serde_json::from_slice(&contents[..split]).ok()?;
+        if header.key != key {
+            return None;
+        }
+        if header.expires.is_some_and(|expires| expires <= now()) {
+            let _ = fs::remove_file(path);
+            return None;
+        }
+        Some(contents[split + 1..].to_vec())
+    }
+
+    fn put(&self, key: &str, value: &[u8], ttl: Ttl) {
+        let header = DiskHeader {
+            key: key.to_string(),
+            expires: match ttl {
+                Ttl::Forever => None,
+                Ttl::For(ttl) => Some(now() + ttl.as_secs()),
+            },
+        };
+        let Ok(mut contents) = serde_json::to_vec(&header) else {
+            return;
+        };
+        contents.push(b'\n');
+        contents.extend_from_slice(value);
+        let _ = fs::create_dir_all(&self.dir).and_then(|_| fs::write(self.path(key), contents));
+    }
+}
+
+/// 64-bit FNV-1a, used for file names that have to stay stable across builds.
+pub(crate) fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_lru() {
+        let cache = MemoryCache::new(2);
+        cache.put("a", b"1", Ttl::Forever);
+        cache.put("b", b"2", Ttl::Forever);
+        assert_eq!(cache.get("a").as_deref(), Some(&b"1"[..]));
+        cache.put("c", b"3", Ttl::Forever);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a").as_deref(), Some(&b"1"[..]));
+        assert_eq!(cache.get("c").as_deref(), Some(&b"3"[..]));
+
+        cache.put("a", b"4", Ttl::Forever);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("a").as_deref(), Some(&b"4"[..]));
+    }
+
+    #[test]
+    fn test_memory_ttl() {
+        let cache = MemoryCache::new(4);
+        cache.put("a", b"1", Ttl::For(Duration::ZERO));
+        cache.put("b", b"2", Ttl::For(Duration::from_secs(60)));
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("b").as_deref(), Some(&b"2"[..]));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(dir.path().join("cache"));
+        assert_eq!(cache.get("a"), None);
+        cache.put("a", b"{\"a\":\n1}", Ttl::Forever);
+        cache.put("b", b"2", Ttl::For(Duration::ZERO));
+        assert_eq!(cache.get("a").as_deref(), Some(&b"{\"a\":\n1}"[..]));
+        assert_eq!(cache.get("b"), None);
+
+        let reopened = DiskCache::new(dir.path().join("cache"));
+        assert_eq!(reopened.get("a").as_deref(), Some(&b"{\"a\":\n1}"[..]));
+    }
+
+    #[test]
+    fn test_policy() {
+        let policy = CachePolicy::default();
+        assert_eq!(policy.get("match-v5.getMatch"), Some(Ttl::Forever));
+        assert_eq!(policy.get("match-v5.getMatchIdsByPUUID"), None);
+        let policy = policy.uncached("match-v5.getMatch");
+        assert_eq!(policy.get("match-v5.getMatch"), None);
+        assert_eq!(CacheStats { hits: 3, misses: 1 }.hit_rate(), 0.75);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_client_cache() {
+        use crate::{
+            retry::RetryPolicy,
+            testing::{self, MATCH_ID, PUUID},
+            Platform, Region, RiotClient,
+        };
+
+        let mut server = mockito::Server::new();
+        let info = server
+            .mock(
+                "GET",
+                format!("/lol/match/v5/matches/{}", MATCH_ID).as_str(),
+            )
+            .with_body(testing::fixture("match_v5/match.json"))
+            .expect(1)
+            .create();
+        let ids = server
+            .mock(
+                "GET",
+                format!("/lol/match/v5/matches/by-puuid/{}/ids", PUUID).as_str(),
+            )
+            .match_query(mockito::Matcher::Any)
+            .with_body(testing::fixture("match_v5/match_ids.json"))
+            .expect(2)
+            .create();
+        let summoner = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", PUUID).as_str(),
+            )
+            .with_body(testing::fixture("summoner_v4/summoner.json"))
+            .expect(2)
+            .create();
+
+        let cache = Arc::new(MemoryCache::new(16));
+        let client = RiotClient::builder("key")
+            .base_url(&server.url())
+            .retry_policy(RetryPolicy::none())
+            .cache(cache.clone())
+            .cache_policy(
+                CachePolicy::default().ttl("summoner-v4.getByPUUID", Ttl::For(Duration::ZERO)),
+            )
+            .build()
+            .unwrap();
+        let matches = client.match_v5(Region::AMERICAS);
+        let first = matches.match_info(&MATCH_ID.parse().unwrap()).unwrap();
+        assert_eq!(
+            matches.match_info(&MATCH_ID.parse().unwrap()).unwrap(),
+            first
+        );
+        for _ in 0..2 {
+            matches
+                .by_puuid(&testing::puuid(), Default::default())
+                .unwrap();
+            client
+                .summoner_v4(Platform::NA1)
+                .by_puuid(&testing::puuid())
+                .unwrap();
+        }
+
+        assert_eq!(client.cache_stats(), CacheStats { hits: 1, misses: 3 });
+        assert_eq!(cache.len(), 2);
+        info.assert();
+        ids.assert();
+        summoner.assert();
+    }
+}
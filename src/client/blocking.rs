@@ -0,0 +1,221 @@
+use std::{sync::Arc, thread, time::Instant};
+
+use serde::de::DeserializeOwned;
+
+use super::{not_found_to_none, ApiRequest, Core, Executor, RiotClientBuilder, Step};
+use crate::{
+    cache::CacheStats,
+    error::Error,
+    league::{
+        challenges_v1::ChallengesV1, champion_mastery_v4::ChampionMasteryV4,
+        champion_v3::ChampionV3, clash_v1::ClashV1, league_exp_v4::LeagueExpV4,
+        league_v4::LeagueV4, lol_status_v4::LolStatusV4, match_v5::MatchV5,
+        spectator_v5::SpectatorV5, summoner_v4::SummonerV4, tournament_stub_v5::TournamentStubV5,
+        tournament_v5::TournamentV5,
+    },
+    rate_limit::RateLimiter,
+    riot::account_v1::AccountV1,
+    tft::{league_v1::TftLeagueV1, match_v1::TftMatchV1, summoner_v1::TftSummonerV1},
+    transport::Transport,
+    Platform, Region,
+};
+
+/// Shared handle to the Riot API. Cloning is cheap and every clone, along with
+/// every endpoint view handed out by it, shares the same connection pool and
+/// configuration.
+#[derive(Debug, Clone)]
+pub struct RiotClient {
+    transport: Arc<dyn Transport>,
+    core: Arc<Core>,
+}
+
+impl RiotClient {
+    pub fn new(key: &str) -> Self {
+        Self::builder(key).build().unwrap()
+    }
+
+    pub fn builder(key: &str) -> RiotClientBuilder {
+        RiotClientBuilder::new(key)
+    }
+
+    pub(super) fn from_parts(transport: Arc<dyn Transport>, core: Core) -> Self {
+        Self {
+            transport,
+            core: Arc::new(core),
+        }
+    }
+
+    pub fn summoner_v4(&self, platform: Platform) -> SummonerV4<Self> {
+        SummonerV4::with_client(self.clone(), platform)
+    }
+
+    /// Also accepts the [`Platform`] a summoner was found on.
+    pub fn match_v5(&self, region: impl Into<Region>) -> MatchV5<Self> {
+        MatchV5::with_client(self.clone(), region)
+    }
+
+    pub fn league_v4(&self, platform: Platform) -> LeagueV4<Self> {
+        LeagueV4::with_client(self.clone(), platform)
+    }
+
+    pub fn league_exp_v4(&self, platform: Platform) -> LeagueExpV4<Self> {
+        LeagueExpV4::with_client(self.clone(), platform)
+    }
+
+    pub fn champion_mastery_v4(&self, platform: Platform) -> ChampionMasteryV4<Self> {
+        ChampionMasteryV4::with_client(self.clone(), platform)
+    }
+
+    pub fn spectator_v5(&self, platform: Platform) -> SpectatorV5<Self> {
+        SpectatorV5::with_client(self.clone(), platform)
+    }
+
+    pub fn champion_v3(&self, platform: Platform) -> ChampionV3<Self> {
+        ChampionV3::with_client(self.clone(), platform)
+    }
+
+    pub fn clash_v1(&self, platform: Platform) -> ClashV1<Self> {
+        ClashV1::with_client(self.clone(), platform)
+    }
+
+    pub fn challenges_v1(&self, platform: Platform) -> ChallengesV1<Self> {
+        ChallengesV1::with_client(self.clone(), platform)
+    }
+
+    pub fn lol_status_v4(&self, platform: Platform) -> LolStatusV4<Self> {
+        LolStatusV4::with_client(self.clone(), platform)
+    }
+
+    pub fn tournament_v5(&self) -> TournamentV5<Self> {
+        TournamentV5::with_client(self.clone())
+    }
+
+    pub fn tournament_stub_v5(&self) -> TournamentStubV5<Self> {
+        TournamentStubV5::with_client(self.clone())
+    }
+
+    pub fn account_v1(&self, region: impl Into<Region>) -> AccountV1<Self> {
+        AccountV1::with_client(self.clone(), region)
+    }
+
+    pub fn tft_summoner_v1(&self, platform: Platform) -> TftSummonerV1<Self> {
+        TftSummonerV1::with_client(self.clone(), platform)
+    }
+
+    pub fn tft_league_v1(&self, platform: Platform) -> TftLeagueV1<Self> {
+        TftLeagueV1::with_client(self.clone(), platform)
+    }
+
+    pub fn tft_match_v1(&self, region: impl Into<Region>) -> TftMatchV1<Self> {
+        TftMatchV1::with_client(self.clone(), region)
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.core.rate_limiter
+    }
+
+    pub fn cache_stats(&self) -> CacheStats {
+        self.core.cache_metrics.stats()
+    }
+
+    fn send<T: DeserializeOwned>(&self, request: &ApiRequest) -> Result<T, Error> {
+        let context = self.core.context(request);
+        if let Some(value) = self.core.cached(request, &context) {
+            return Ok(value);
+        }
+        let started = Instant::now();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            while let Some(wait) = self.core.acquire(request, &context)? {
+                thread::sleep(wait);
+            }
+
+            let response = self
+                .transport
+                .send(self.core.http_request(request, &context))
+                .map_err(|source| Error::Transport {
+                    context: context.clone(),
+                    source,
+                })?;
+            let step = self
+                .core
+                .handle(request, &context, attempt, started, &response);
+            match step {
+                Step::Done(result) => return result,
+                Step::Retry(delay) => thread::sleep(delay),
+            }
+        }
+    }
+}
+
+impl Executor for RiotClient {
+    type Output<T: DeserializeOwned + Send + 'static> = Result<T, Error>;
+
+    fn execute<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest,
+    ) -> Self::Output<T> {
+        self.send(&request)
+    }
+
+    fn execute_optional<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest,
+    ) -> Self::Output<Option<T>> {
+        not_found_to_none(self.send(&request))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Route;
+
+    #[test]
+    fn test_url() {
+        let client = RiotClient::new("key");
+        assert_eq!(
+            client
+                .core
+                .url(Platform::NA1.into(), "/lol/summoner/v4/summoners/abc"),
+            "https://na1.api.riotgames.com/lol/summoner/v4/summoners/abc"
+        );
+        assert_eq!(
+            client
+                .core
+                .url(Region::AMERICAS.into(), "/lol/match/v5/matches/NA1_1"),
+            "https://americas.api.riotgames.com/lol/match/v5/matches/NA1_1"
+        );
+        assert_eq!(
+            client
+                .core
+                .url(Platform::VN2.region().into(), "/lol/match/v5/matches/VN2_1"),
+            "https://sea.api.riotgames.com/lol/match/v5/matches/VN2_1"
+        );
+    }
+
+    #[test]
+    fn test_base_url() {
+        let client = RiotClient::builder("key")
+            .base_url("http://127.0.0.1:8080/")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.core.url(
+                Route::Platform(Platform::EUW1),
+                "/lol/summoner/v4/summoners/abc"
+            ),
+            "http://127.0.0.1:8080/lol/summoner/v4/summoners/abc"
+        );
+    }
+
+    #[test]
+    fn test_views_share_client() {
+        let client = RiotClient::new("key");
+        let summoner = client.summoner_v4(Platform::NA1);
+        let matches = client.match_v5(Region::AMERICAS);
+        assert!(Arc::ptr_eq(&summoner.client().core, &client.core));
+        assert!(Arc::ptr_eq(&matches.client().core, &client.core));
+    }
+}
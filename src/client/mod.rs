@@ -0,0 +1,397 @@
+#[cfg(feature = "blocking")]
+mod blocking;
+#[cfg(feature = "async")]
+mod non_blocking;
+
+#[cfg(feature = "async")]
+use std::{future::Future, pin::Pin};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+    Method, StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(feature = "blocking")]
+pub use self::blocking::RiotClient;
+#[cfg(feature = "async")]
+pub use self::non_blocking::AsyncRiotClient;
+#[cfg(feature = "async")]
+use crate::transport::{AsyncReqwestTransport, AsyncTransport};
+#[cfg(feature = "blocking")]
+use crate::transport::{ReqwestTransport, Transport};
+use crate::{
+    cache::{Cache, CacheMetrics, CachePolicy, Ttl},
+    error::{DecodeError, Error, RequestContext, StatusError},
+    rate_limit::{RateLimitHeaders, RateLimitMode, RateLimiter},
+    retry::RetryPolicy,
+    transport::{HttpRequest, HttpResponse},
+    Route,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://{route}.api.riotgames.com";
+
+#[cfg(feature = "async")]
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send + 'static>>;
+
+/// A single call to the Riot API, as built by an endpoint view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiRequest {
+    pub route: Route,
+    /// Riot's method id, e.g. `match-v5.getMatch`, used to key method rate limits.
+    pub method: &'static str,
+    pub path: String,
+    pub http_method: Method,
+    /// JSON request body, for the few endpoints that take one.
+    pub body: Option<Vec<u8>>,
+}
+
+impl ApiRequest {
+    /// A `GET` request without a body.
+    pub fn new(route: Route, method: &'static str, path: String) -> Self {
+        Self {
+            route,
+            method,
+            path,
+            http_method: Method::GET,
+            body: None,
+        }
+    }
+
+    /// Sends the request as `http_method` with `body` serialized to JSON.
+    pub fn with_json<B: Serialize>(mut self, http_method: Method, body: &B) -> Self {
+        self.http_method = http_method;
+        self.body = Some(serde_json::to_vec(body).expect("request body serializes to JSON"));
+        self
+    }
+}
+
+/// Runs requests built by the endpoint views. The blocking `RiotClient`
+/// returns results directly while the `AsyncRiotClient` returns futures, so
+/// every endpoint is written once and returns the same types on both.
+pub trait Executor: Clone {
+    type Output<T: DeserializeOwned + Send + 'static>;
+
+    fn execute<T: DeserializeOwned + Send + 'static>(&self, request: ApiRequest)
+        -> Self::Output<T>;
+
+    /// Like [`execute`](Self::execute), but resolves a `404 Not Found` to
+    /// `None` for endpoints where it means "nothing there" rather than a failure.
+    fn execute_optional<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest,
+    ) -> Self::Output<Option<T>>;
+}
+
+pub(crate) fn not_found_to_none<T>(result: Result<T, Error>) -> Result<Option<T>, Error> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(err) if err.is_not_found() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Builds a query string such as `?start=0&count=20` from the parameters that
+/// are set, or an empty string when none are.
+pub(crate) fn query_string(params: &[(&str, Option<String>)]) -> String {
+    let query = params
+        .iter()
+        .filter_map(|(key, value)| Some(format!("{}={}", key, value.as_ref()?)))
+        .collect::<Vec<_>>()
+        .join("&");
+    if query.is_empty() {
+        query
+    } else {
+        format!("?{}", query)
+    }
+}
+
+/// Configuration and rate limit state shared by every clone of a client.
+#[derive(Debug)]
+pub(crate) struct Core {
+    token: HeaderValue,
+    base_url: String,
+    rate_limiter: RateLimiter,
+    rate_limit_mode: RateLimitMode,
+    retry_policy: RetryPolicy,
+    retain_decode_body: bool,
+    cache: Option<Arc<dyn Cache>>,
+    cache_policy: CachePolicy,
+    cache_metrics: CacheMetrics,
+}
+
+impl Core {
+    pub(crate) fn url(&self, route: Route, path: &str) -> String {
+        let base = self.base_url.replace("{route}", &route.to_string());
+        format!("{}{}", base.trim_end_matches('/'), path)
+    }
+
+    pub(crate) fn context(&self, request: &ApiRequest) -> RequestContext {
+        RequestContext {
+            method: request.method,
+            url: self.url(request.route, &request.path),
+        }
+    }
+
+    pub(crate) fn http_request(
+        &self,
+        request: &ApiRequest,
+        context: &RequestContext,
+    ) -> HttpRequest {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Riot-Token", self.token.clone());
+        if request.body.is_some() {
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        }
+        HttpRequest {
+            method: request.http_method.clone(),
+            url: context.url.clone(),
+            headers,
+            body: request.body.clone(),
+        }
+    }
+
+    fn cache_ttl(&self, request: &ApiRequest) -> Option<(&dyn Cache, Ttl)> {
+        let cache = self.cache.as_deref()?;
+        Some((cache, self.cache_policy.get(request.method)?))
+    }
+
+    /// Returns a fresh cached response for `request`. An entry that no longer
+    /// decodes, e.g. after a type changed, counts as a miss and is refetched.
+    pub(crate) fn cached<T: DeserializeOwned>(
+        &self,
+        request: &ApiRequest,
+        context: &RequestContext,
+    ) -> Option<T> {
+        let (cache, _) = self.cache_ttl(request)?;
+        let value = cache
+            .get(&context.url)
+            .and_then(|body| serde_json::from_slice(&body).ok());
+        match value {
+            Some(_) => self.cache_metrics.hit(),
+            None => self.cache_metrics.miss(),
+        }
+        value
+    }
+
+    /// Reserves a rate limit slot for `request`, returning how long to wait
+    /// before trying again when the client is configured to block.
+    pub(crate) fn acquire(
+        &self,
+        request: &ApiRequest,
+        context: &RequestContext,
+    ) -> Result<Option<Duration>, Error> {
+        let wait = match self.rate_limit_mode {
+            RateLimitMode::Disabled => return Ok(None),
+            _ => self.rate_limiter.acquire(request.route, request.method),
+        };
+        match (self.rate_limit_mode, wait) {
+            (RateLimitMode::Fail, Some(wait)) => Err(Error::RateLimited {
+                context: context.clone(),
+                wait,
+            }),
+            (_, wait) => Ok(wait),
+        }
+    }
+
+    /// Records a response's rate limit headers and turns it into either the
+    /// decoded result or a delay after which the request should be retried.
+    pub(crate) fn handle<T: DeserializeOwned>(
+        &self,
+        request: &ApiRequest,
+        context: &RequestContext,
+        attempt: u32,
+        started: Instant,
+        response: &HttpResponse,
+    ) -> Step<T> {
+        let HttpResponse {
+            status,
+            headers,
+            body,
+        } = response;
+        let status = *status;
+        self.rate_limiter
+            .update(request.route, request.method, headers);
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            self.rate_limiter
+                .on_rate_limited(request.route, request.method, headers);
+        }
+
+        if status.is_success() {
+            let result = self.decode(context, body);
+            if let (Ok(_), Some((cache, ttl))) = (&result, self.cache_ttl(request)) {
+                cache.put(&context.url, body, ttl);
+            }
+            return Step::Done(result);
+        }
+        // A 429 was never processed, but a POST that failed on the server may
+        // have been, and retrying it could e.g. create tournament codes twice.
+        let retryable =
+            request.http_method != Method::POST || status == StatusCode::TOO_MANY_REQUESTS;
+        if let Some(delay) = retryable
+            .then(|| {
+                self.retry_policy
+                    .delay(attempt, started.elapsed(), status, headers)
+            })
+            .flatten()
+        {
+            return Step::Retry(delay);
+        }
+        Step::Done(Err(Error::Status(Box::new(StatusError {
+            context: context.clone(),
+            status,
+            body: serde_json::from_slice(body).ok(),
+            rate_limit: RateLimitHeaders::from_headers(headers),
+        }))))
+    }
+
+    fn decode<T: DeserializeOwned>(
+        &self,
+        context: &RequestContext,
+        body: &[u8],
+    ) -> Result<T, Error> {
+        // Endpoints that return nothing, like tournament code updates, decode to `()`.
+        let body = if body.is_empty() { b"null" } else { body };
+        DecodeError::from_slice(body, self.retain_decode_body).map_err(|source| Error::Decode {
+            context: context.clone(),
+            source,
+        })
+    }
+}
+
+pub(crate) enum Step<T> {
+    Done(Result<T, Error>),
+    Retry(Duration),
+}
+
+#[derive(Debug)]
+pub struct RiotClientBuilder {
+    key: String,
+    base_url: String,
+    rate_limit_mode: RateLimitMode,
+    retry_policy: RetryPolicy,
+    retain_decode_body: bool,
+    cache: Option<Arc<dyn Cache>>,
+    cache_policy: CachePolicy,
+    #[cfg(feature = "blocking")]
+    transport: Option<Arc<dyn Transport>>,
+    #[cfg(feature = "async")]
+    async_transport: Option<Arc<dyn AsyncTransport>>,
+}
+
+impl RiotClientBuilder {
+    pub fn new(key: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            rate_limit_mode: RateLimitMode::default(),
+            retry_policy: RetryPolicy::default(),
+            retain_decode_body: false,
+            cache: None,
+            cache_policy: CachePolicy::default(),
+            #[cfg(feature = "blocking")]
+            transport: None,
+            #[cfg(feature = "async")]
+            async_transport: None,
+        }
+    }
+
+    /// Overrides the host every request is sent to. `{route}` is replaced with
+    /// the lowercase platform or region routing value, e.g. `na1` or `americas`.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_string();
+        self
+    }
+
+    pub fn rate_limit_mode(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limit_mode = mode;
+        self
+    }
+
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Keeps the raw response on `Error::Decode` so it can be saved and the
+    /// failure replayed offline.
+    pub fn retain_decode_body(mut self, retain: bool) -> Self {
+        self.retain_decode_body = retain;
+        self
+    }
+
+    /// Serves repeated requests from `cache` for the methods `cache_policy`
+    /// gives a TTL, so they cost no API quota.
+    pub fn cache(mut self, cache: impl Cache + 'static) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    pub fn cache_policy(mut self, policy: CachePolicy) -> Self {
+        self.cache_policy = policy;
+        self
+    }
+
+    /// Sends requests through `transport` instead of a default `reqwest`
+    /// client, e.g. to serve recorded responses.
+    #[cfg(feature = "blocking")]
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    #[cfg(feature = "async")]
+    pub fn async_transport(mut self, transport: impl AsyncTransport + 'static) -> Self {
+        self.async_transport = Some(Arc::new(transport));
+        self
+    }
+
+    #[cfg(feature = "blocking")]
+    pub fn build(mut self) -> Result<RiotClient, Error> {
+        let transport = match self.transport.take() {
+            Some(transport) => transport,
+            None => {
+                let client = reqwest::blocking::Client::builder()
+                    .build()
+                    .map_err(|err| Error::config("couldn't build the HTTP client", err))?;
+                Arc::new(ReqwestTransport::new(client))
+            }
+        };
+        Ok(RiotClient::from_parts(transport, self.core()?))
+    }
+
+    #[cfg(feature = "async")]
+    pub fn build_async(mut self) -> Result<AsyncRiotClient, Error> {
+        let transport = match self.async_transport.take() {
+            Some(transport) => transport,
+            None => {
+                let client = reqwest::Client::builder()
+                    .build()
+                    .map_err(|err| Error::config("couldn't build the HTTP client", err))?;
+                Arc::new(AsyncReqwestTransport::new(client))
+            }
+        };
+        Ok(AsyncRiotClient::from_parts(transport, self.core()?))
+    }
+
+    fn core(self) -> Result<Core, Error> {
+        let mut token = HeaderValue::from_str(&self.key)
+            .map_err(|err| Error::config("the API key is not a valid header value", err))?;
+        token.set_sensitive(true);
+        Ok(Core {
+            token,
+            base_url: self.base_url,
+            rate_limiter: RateLimiter::new(),
+            rate_limit_mode: self.rate_limit_mode,
+            retry_policy: self.retry_policy,
+            retain_decode_body: self.retain_decode_body,
+            cache: self.cache,
+            cache_policy: self.cache_policy,
+            cache_metrics: CacheMetrics::default(),
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use std::{sync::Arc, time::Instant};
+
+use serde::de::DeserializeOwned;
+
+use super::{not_found_to_none, ApiRequest, BoxFuture, Core, Executor, RiotClientBuilder, Step};
+use crate::{
+    cache::CacheStats,
+    error::Error,
+    league::{
+        challenges_v1::ChallengesV1, champion_mastery_v4::ChampionMasteryV4,
+        champion_v3::ChampionV3, clash_v1::ClashV1, league_exp_v4::LeagueExpV4,
+        league_v4::LeagueV4, lol_status_v4::LolStatusV4, match_v5::MatchV5,
+        spectator_v5::SpectatorV5, summoner_v4::SummonerV4, tournament_stub_v5::TournamentStubV5,
+        tournament_v5::TournamentV5,
+    },
+    rate_limit::RateLimiter,
+    riot::account_v1::AccountV1,
+    tft::{league_v1::TftLeagueV1, match_v1::TftMatchV1, summoner_v1::TftSummonerV1},
+    transport::AsyncTransport,
+    Platform, Region,
+};
+
+/// Async counterpart of `RiotClient`. Endpoint views handed out by it return
+/// futures resolving to the same types.
+#[derive(Debug, Clone)]
+pub struct AsyncRiotClient {
+    transport: Arc<dyn AsyncTransport>,
+    core: Arc<Core>,
+}
+
+impl AsyncRiotClient {
+    pub fn new(key: &str) -> Self {
+        Self::builder(key).build_async().unwrap()
+    }
+
+    pub fn builder(key: &str) -> RiotClientBuilder {
+        RiotClientBuilder::new(key)
+    }
+
+    pub(super) fn from_parts(transport: Arc<dyn AsyncTransport>, core: Core) -> Self {
+        Self {
+            transport,
+            core: Arc::new(core),
+        }
+    }
+
+    pub fn summoner_v4(&self, platform: Platform) -> SummonerV4<Self> {
+        SummonerV4::with_client(self.clone(), platform)
+    }
+
+    /// Also accepts the [`Platform`] a summoner was found on.
+    pub fn match_v5(&self, region: impl Into<Region>) -> MatchV5<Self> {
+        MatchV5::with_client(self.clone(), region)
+    }
+
+    pub fn league_v4(&self, platform: Platform) -> LeagueV4<Self> {
+        LeagueV4::with_client(self.clone(), platform)
+    }
+
+    pub fn league_exp_v4(&self, platform: Platform) -> LeagueExpV4<Self> {
+        LeagueExpV4::with_client(self.clone(), platform)
+    }
+
+    pub fn champion_mastery_v4(&self, platform: Platform) -> ChampionMasteryV4<Self> {
+        ChampionMasteryV4::with_client(self.clone(), platform)
+    }
+
+    pub fn spectator_v5(&self, platform: Platform) -> SpectatorV5<Self> {
+        SpectatorV5::with_client(self.clone(), platform)
+    }
+
+    pub fn champion_v3(&self, platform: Platform) -> ChampionV3<Self> {
+        ChampionV3::with_client(self.clone(), platform)
+    }
+
+    pub fn clash_v1(&self, platform: Platform) -> ClashV1<Self> {
+        ClashV1::with_client(self.clone(), platform)
+    }
+
+    pub fn challenges_v1(&self, platform: Platform) -> ChallengesV1<Self> {
+        ChallengesV1::with_client(self.clone(), platform)
+    }
+
+    pub fn lol_status_v4(&self, platform: Platform) -> LolStatusV4<Self> {
+        LolStatusV4::with_client(self.clone(), platform)
+    }
+
+    pub fn tournament_v5(&self) -> TournamentV5<Self> {
+        TournamentV5::with_client(self.clone())
+    }
+
+    pub fn tournament_stub_v5(&self) -> TournamentStubV5<Self> {
+        TournamentStubV5::with_client(self.clone())
+    }
+
+    pub fn account_v1(&self, region: impl Into<Region>) -> AccountV1<Self> {
+        AccountV1::with_client(self.clone(), region)
+    }
+
+    pub fn tft_summoner_v1(&self, platform: Platform) -> TftSummonerV1<Self> {
+        TftSummonerV1::with_client(self.clone(), platform)
+    }
+
+    pub fn tft_league_v1(&self, platform: Platform) -> TftLeagueV1<Self> {
+        TftLeagueV1::with_client(self.clone(), platform)
+    }
+
+    pub fn tft_match_v1(&self, region: impl Into<Region>) -> TftMatchV1<Self> {
+        TftMatchV1::with_client(self.clone(), region)
+    }
+
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.core.rate_limiter
+    }
+
+    pub fn cache_stats(&self) -> CacheStats {
+        self.core.cache_metrics.stats()
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: &ApiRequest) -> Result<T, Error> {
+        let context = self.core.context(request);
+        if let Some(value) = self.core.cached(request, &context) {
+            return Ok(value);
+        }
+        let started = Instant::now();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            while let Some(wait) = self.core.acquire(request, &context)? {
+                tokio::time::sleep(wait).await;
+            }
+
+            let response = self
+                .transport
+                .send(self.core.http_request(request, &context))
+                .await
+                .map_err(|source| Error::Transport {
+                    context: context.clone(),
+                    source,
+                })?;
+            let step = self
+                .core
+                .handle(request, &context, attempt, started, &response);
+            match step {
+                Step::Done(result) => return result,
+                Step::Retry(delay) => tokio::time::sleep(delay).await,
+            }
+        }
+    }
+}
+
+impl Executor for AsyncRiotClient {
+    type Output<T: DeserializeOwned + Send + 'static> = BoxFuture<Result<T, Error>>;
+
+    fn execute<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest,
+    ) -> Self::Output<T> {
+        let client = self.clone();
+        Box::pin(async move { client.send(&request).await })
+    }
+
+    fn execute_optional<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest,
+    ) -> Self::Output<Option<T>> {
+        let client = self.clone();
+        Box::pin(async move { not_found_to_none(client.send(&request).await) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ids::MatchId, testing};
+
+    #[tokio::test]
+    async fn test_async_endpoints() {
+        let mut server = mockito::Server::new_async().await;
+        let summoner = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", testing::PUUID).as_str(),
+            )
+            .with_body(r#"{"id":"id","accountId":"account","puuid":"puuid","name":"name","profileIconId":1,"revisionDate":1,"summonerLevel":1}"#)
+            .create_async()
+            .await;
+        let ids = server
+            .mock(
+                "GET",
+                format!(
+                    "/lol/match/v5/matches/by-puuid/{}/ids?start=0&count=2",
+                    testing::PUUID
+                )
+                .as_str(),
+            )
+            .with_body(r#"["NA1_1","NA1_2"]"#)
+            .create_async()
+            .await;
+
+        let client = AsyncRiotClient::builder("key")
+            .base_url(&server.url())
+            .build_async()
+            .unwrap();
+        let res = client
+            .summoner_v4(Platform::NA1)
+            .by_puuid(&testing::puuid())
+            .await
+            .unwrap();
+        assert_eq!(res.puuid, "puuid");
+
+        let options = crate::league::match_v5::ByPUUIDOptions {
+            start_time: None,
+            end_time: None,
+            queue: None,
+            match_type: None,
+            start: Some(0),
+            count: Some(2),
+        };
+        let res = client
+            .match_v5(Region::AMERICAS)
+            .by_puuid(&testing::puuid(), options)
+            .await
+            .unwrap();
+        assert_eq!(
+            res.ids,
+            [
+                MatchId::new(Platform::NA1, 1),
+                MatchId::new(Platform::NA1, 2)
+            ]
+        );
+        summoner.assert_async().await;
+        ids.assert_async().await;
+    }
+}
@@ -1,13 +1,398 @@
+use std::{error::Error as StdError, fmt, time::Duration};
+
 use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::rate_limit::RateLimitHeaders;
+
+pub type BoxError = Box<dyn StdError + Send + Sync + 'static>;
+
+/// Identifies the call an error came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// Riot's method id, e.g. `match-v5.getMatch`.
+    pub method: &'static str,
+    pub url: String,
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.method, self.url)
+    }
+}
+
+/// The body Riot sends with most error responses, e.g.
+/// `{"status": {"message": "Data not found", "status_code": 404}}`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiotErrorBody {
+    pub status: RiotErrorStatus,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiotErrorStatus {
+    pub message: String,
+    pub status_code: u16,
+}
+
+/// A response with a status other than `200 OK`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusError {
+    pub context: RequestContext,
+    pub status: StatusCode,
+    /// Riot's error body, when the response had one that could be parsed.
+    pub body: Option<RiotErrorBody>,
+    pub rate_limit: RateLimitHeaders,
+}
 
+/// A response body that didn't match the expected type.
 #[derive(Debug)]
+pub struct DecodeError {
+    /// Path to the offending value, e.g. `info.participants[3].perks.styles[0]`.
+    pub path: String,
+    pub source: serde_json::Error,
+    /// The raw response, kept when the client is built with
+    /// `retain_decode_body(true)` so failures can be replayed offline.
+    pub body: Option<Vec<u8>>,
+}
+
+impl DecodeError {
+    pub(crate) fn from_slice<T: DeserializeOwned>(
+        body: &[u8],
+        retain_body: bool,
+    ) -> Result<T, Self> {
+        let mut deserializer = serde_json::Deserializer::from_slice(body);
+        let result = match serde_path_to_error::deserialize(&mut deserializer) {
+            Ok(value) => deserializer
+                .end()
+                .map(|_| value)
+                .map_err(|err| (".".to_string(), err)),
+            Err(err) => Err((err.path().to_string(), err.into_inner())),
+        };
+        result.map_err(|(path, source)| Self {
+            path,
+            source,
+            body: retain_body.then(|| body.to_vec()),
+        })
+    }
+
+    pub fn body_str(&self) -> Option<&str> {
+        std::str::from_utf8(self.body.as_deref()?).ok()
+    }
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value at `{}`", self.path)
+    }
+}
+
+impl StdError for DecodeError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
-    Message(String),
-    StatusCode(StatusCode),
+    /// The request could not be sent or its response could not be read.
+    Transport {
+        context: RequestContext,
+        source: BoxError,
+    },
+    /// Riot answered with an unsuccessful status, after any retries.
+    Status(Box<StatusError>),
+    /// The response body did not match the expected type.
+    Decode {
+        context: RequestContext,
+        source: DecodeError,
+    },
+    /// The request was not sent because a rate limit bucket is full and the
+    /// client is configured with `RateLimitMode::Fail`.
+    RateLimited {
+        context: RequestContext,
+        wait: Duration,
+    },
+    /// The client could not be built from its configuration.
+    Config {
+        message: String,
+        source: Option<BoxError>,
+    },
 }
 
 impl Error {
-    pub fn new_message(msg: &str) -> Self {
-        Self::Message(msg.to_string())
+    pub fn context(&self) -> Option<&RequestContext> {
+        match self {
+            Self::Transport { context, .. }
+            | Self::Decode { context, .. }
+            | Self::RateLimited { context, .. } => Some(context),
+            Self::Status(error) => Some(&error.context),
+            Self::Config { .. } => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Status(error) => Some(error.status),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(StatusCode::NOT_FOUND)
+    }
+
+    /// Riot's error message, when the response carried one.
+    pub fn riot_message(&self) -> Option<&str> {
+        match self {
+            Self::Status(error) => error.body.as_ref().map(|body| body.status.message.as_str()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn config(message: &str, source: impl Into<BoxError>) -> Self {
+        Self::Config {
+            message: message.to_string(),
+            source: Some(source.into()),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transport { context, .. } => write!(f, "request to {} failed", context),
+            Self::Status(error) => {
+                write!(f, "{} returned {}", error.context, error.status)?;
+                if let Some(body) = &error.body {
+                    write!(f, ": {}", body.status.message)?;
+                }
+                Ok(())
+            }
+            Self::Decode { context, source } => write!(
+                f,
+                "couldn't deserialize response from {} at `{}`",
+                context, source.path
+            ),
+            Self::RateLimited { context, wait } => write!(
+                f,
+                "request to {} would exceed the rate limit, retry in {:?}",
+                context, wait
+            ),
+            Self::Config { message, .. } => write!(f, "invalid client configuration: {}", message),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Transport { source, .. } => Some(source.as_ref()),
+            Self::Decode { source, .. } => Some(source),
+            Self::Config {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> RequestContext {
+        RequestContext {
+            method: "summoner-v4.getByPUUID",
+            url: "https://na1.api.riotgames.com/lol/summoner/v4/summoners/by-puuid/puuid"
+                .to_string(),
+        }
+    }
+
+    #[test]
+    fn test_status_display() {
+        let error = Error::Status(Box::new(StatusError {
+            context: context(),
+            status: StatusCode::NOT_FOUND,
+            body: serde_json::from_str(
+                r#"{"status":{"message":"Data not found - summoner not found","status_code":404}}"#,
+            )
+            .ok(),
+            rate_limit: RateLimitHeaders::default(),
+        }));
+        assert!(error.is_not_found());
+        assert_eq!(
+            error.riot_message(),
+            Some("Data not found - summoner not found")
+        );
+        assert_eq!(
+            error.to_string(),
+            "summoner-v4.getByPUUID (https://na1.api.riotgames.com/lol/summoner/v4/summoners/by-puuid/puuid) returned 404 Not Found: Data not found - summoner not found"
+        );
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn test_decode_source() {
+        let source = DecodeError::from_slice::<RiotErrorBody>(b"{", false).unwrap_err();
+        let error = Error::Decode {
+            context: context(),
+            source,
+        };
+        assert_eq!(error.context(), Some(&context()));
+        let source = error.source().unwrap();
+        assert!(source.downcast_ref::<DecodeError>().is_some());
+        assert!(source
+            .source()
+            .unwrap()
+            .downcast_ref::<serde_json::Error>()
+            .is_some());
+    }
+
+    #[test]
+    fn test_decode_path() {
+        use crate::{league::match_v5::MatchInfo, testing};
+
+        let mut value: serde_json::Value =
+            serde_json::from_str(&testing::fixture("match_v5/match.json")).unwrap();
+        value["info"]["participants"][3]["perks"]["styles"][0]["style"] = "8000".into();
+        let body = serde_json::to_vec(&value).unwrap();
+
+        let error = DecodeError::from_slice::<MatchInfo>(&body, true).unwrap_err();
+        assert_eq!(error.path, "info.participants[3].perks.styles[0].style");
+        assert_eq!(error.body.as_deref(), Some(body.as_slice()));
+        assert!(error.body_str().unwrap().contains("\"participants\""));
+
+        let error = DecodeError::from_slice::<MatchInfo>(&body, false).unwrap_err();
+        assert_eq!(error.body, None);
+
+        let error = DecodeError::from_slice::<Vec<String>>(b"[\"NA1_1\"] x", false).unwrap_err();
+        assert_eq!(error.path, ".");
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_client_status_error() {
+        use crate::{rate_limit::RateLimitType, retry::RetryPolicy, testing, Platform, RiotClient};
+
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", testing::PUUID).as_str(),
+            )
+            .with_status(404)
+            .with_header("X-App-Rate-Limit", "20:1")
+            .with_header("X-App-Rate-Limit-Count", "1:1")
+            .with_body(r#"{"status":{"message":"Data not found","status_code":404}}"#)
+            .create();
+        let forbidden = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/{}", testing::SUMMONER_ID).as_str(),
+            )
+            .with_status(429)
+            .with_header("X-Rate-Limit-Type", "service")
+            .create();
+
+        let client = RiotClient::builder("key")
+            .base_url(&server.url())
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+        let api = client.summoner_v4(Platform::NA1);
+
+        match api.by_puuid(&testing::puuid()) {
+            Err(Error::Status(error)) => {
+                assert_eq!(error.status, StatusCode::NOT_FOUND);
+                assert_eq!(error.context.method, "summoner-v4.getByPUUID");
+                assert_eq!(
+                    error.context.url,
+                    format!(
+                        "{}/lol/summoner/v4/summoners/by-puuid/{}",
+                        server.url(),
+                        testing::PUUID
+                    )
+                );
+                assert_eq!(error.body.unwrap().status.message, "Data not found");
+                assert_eq!(error.rate_limit.app_limit.as_deref(), Some("20:1"));
+            }
+            other => panic!("expected status error, got {:?}", other),
+        }
+
+        match api.by_summoner_id(&testing::SUMMONER_ID.parse().unwrap()) {
+            Err(Error::Status(error)) => {
+                assert_eq!(error.body, None);
+                assert_eq!(error.rate_limit.limit_type, Some(RateLimitType::Service));
+            }
+            other => panic!("expected status error, got {:?}", other),
+        }
+        mock.assert();
+        forbidden.assert();
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_client_decode_error() {
+        use crate::{testing, Platform, RiotClient};
+
+        let mut server = mockito::Server::new();
+        let body = r#"{"id":"id","accountId":"account","puuid":"puuid","name":"name","profileIconId":"1","revisionDate":1,"summonerLevel":1}"#;
+        let mock = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", testing::PUUID).as_str(),
+            )
+            .with_body(body)
+            .expect(2)
+            .create();
+
+        let client = RiotClient::builder("key")
+            .base_url(&server.url())
+            .retain_decode_body(true)
+            .build()
+            .unwrap();
+        match client
+            .summoner_v4(Platform::NA1)
+            .by_puuid(&testing::puuid())
+        {
+            Err(Error::Decode { context, source }) => {
+                assert_eq!(context.method, "summoner-v4.getByPUUID");
+                assert_eq!(source.path, "profileIconId");
+                assert_eq!(source.body_str(), Some(body));
+            }
+            other => panic!("expected decode error, got {:?}", other),
+        }
+
+        let client = RiotClient::builder("key")
+            .base_url(&server.url())
+            .build()
+            .unwrap();
+        match client
+            .summoner_v4(Platform::NA1)
+            .by_puuid(&testing::puuid())
+        {
+            Err(Error::Decode { source, .. }) => assert_eq!(source.body, None),
+            other => panic!("expected decode error, got {:?}", other),
+        }
+        mock.assert();
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_client_transport_error() {
+        use crate::{testing, Platform, RiotClient};
+
+        let client = RiotClient::builder("key")
+            .base_url("http://127.0.0.1:1")
+            .build()
+            .unwrap();
+        let error = client
+            .summoner_v4(Platform::NA1)
+            .by_puuid(&testing::puuid())
+            .unwrap_err();
+        assert!(matches!(error, Error::Transport { .. }));
+        assert!(error.source().is_some());
     }
 }
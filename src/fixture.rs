@@ -0,0 +1,429 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[cfg(feature = "blocking")]
+use crate::transport::Transport;
+use crate::{
+    cache::fnv1a,
+    error::BoxError,
+    transport::{HttpRequest, HttpResponse},
+};
+#[cfg(feature = "async")]
+use crate::{client::BoxFuture, transport::AsyncTransport};
+
+const REDACTED: &str = "REDACTED";
+
+/// A recorded response, stored as one JSON file per method and URL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    /// The body, when it was JSON. Kept as a value so fixtures stay readable.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl Recording {
+    /// Captures `response` to `request`, replacing the API key wherever it appears.
+    pub fn new(request: &HttpRequest, response: &HttpResponse) -> Self {
+        let key = request
+            .headers
+            .get("X-Riot-Token")
+            .and_then(|value| value.to_str().ok())
+            .filter(|key| !key.is_empty());
+        let redact = |value: &str| match key {
+            Some(key) => value.replace(key, REDACTED),
+            None => value.to_string(),
+        };
+
+        let headers = response
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), redact(value.to_str().ok()?))))
+            .collect();
+        let body = redact(&String::from_utf8_lossy(&response.body));
+        let (json, text) = match serde_json::from_str(&body) {
+            Ok(value) => (Some(value), None),
+            Err(_) => (None, Some(body)),
+        };
+
+        Self {
+            method: request.method.to_string(),
+            url: redact(&redact_query(&request.url)),
+            status: response.status.as_u16(),
+            headers,
+            json,
+            text,
+        }
+    }
+
+    pub fn to_response(&self) -> Result<HttpResponse, BoxError> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            headers.append(
+                HeaderName::from_bytes(name.as_bytes())?,
+                HeaderValue::from_str(value)?,
+            );
+        }
+        let body = match (&self.json, &self.text) {
+            (Some(json), _) => serde_json::to_vec(json)?,
+            (None, Some(text)) => text.clone().into_bytes(),
+            (None, None) => Vec::new(),
+        };
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(self.status)?,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Drops an `api_key` query parameter, the other place Riot accepts a key.
+fn redact_query(url: &str) -> String {
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_string();
+    };
+    let query: Vec<&str> = query
+        .split('&')
+        .filter(|pair| !pair.starts_with("api_key="))
+        .collect();
+    if query.is_empty() {
+        base.to_string()
+    } else {
+        format!("{}?{}", base, query.join("&"))
+    }
+}
+
+/// The file a request is recorded to inside a fixture directory, e.g.
+/// `GET_na1.api.riotgames.com_lol_summoner_v4_summoners_by-puuid_abc-0123456789abcdef.json`.
+/// The hash keeps URLs that only differ in punctuation apart.
+pub fn fixture_path(dir: &Path, method: &str, url: &str) -> PathBuf {
+    let url = redact_query(url);
+    let trimmed = url.split_once("://").map_or(url.as_str(), |(_, rest)| rest);
+    let mut name: String = trimmed
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '.' => c,
+            _ => '_',
+        })
+        .take(120)
+        .collect();
+    name = format!(
+        "{}_{}-{:016x}.json",
+        method,
+        name,
+        fnv1a(&format!("{} {}", method, url))
+    );
+    dir.join(name)
+}
+
+/// Wraps another transport and saves every response it returns to `dir`.
+#[derive(Debug)]
+pub struct RecordingTransport<T> {
+    inner: T,
+    dir: PathBuf,
+}
+
+impl<T> RecordingTransport<T> {
+    pub fn new(inner: T, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            dir: dir.into(),
+        }
+    }
+
+    fn record(&self, request: &HttpRequest, response: &HttpResponse) -> Result<(), BoxError> {
+        let recording = Recording::new(request, response);
+        fs::create_dir_all(&self.dir)?;
+        let path = fixture_path(&self.dir, &recording.method, &recording.url);
+        fs::write(path, serde_json::to_vec_pretty(&recording)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T: Transport> Transport for RecordingTransport<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, BoxError> {
+        let response = self.inner.send(request.clone())?;
+        self.record(&request, &response)?;
+        Ok(response)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: AsyncTransport + Clone + 'static> AsyncTransport for RecordingTransport<T> {
+    fn send(&self, request: HttpRequest) -> BoxFuture<Result<HttpResponse, BoxError>> {
+        let recorder = Self::new(self.inner.clone(), self.dir.clone());
+        Box::pin(async move {
+            let response = recorder.inner.send(request.clone()).await?;
+            recorder.record(&request, &response)?;
+            Ok(response)
+        })
+    }
+}
+
+/// What a [`ReplayTransport`] does with a request that has no recording.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MissingFixture {
+    /// Fail the request with an [`UnrecordedRequest`] transport error.
+    #[default]
+    Error,
+    /// Panic, so a test can't accidentally pass on a fallback path.
+    Panic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnrecordedRequest {
+    pub method: String,
+    pub url: String,
+    pub path: PathBuf,
+}
+
+impl fmt::Display for UnrecordedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "no recording for {} {} (expected {})",
+            self.method,
+            self.url,
+            self.path.display()
+        )
+    }
+}
+
+impl std::error::Error for UnrecordedRequest {}
+
+/// Serves responses previously saved by a [`RecordingTransport`] without
+/// touching the network.
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    dir: PathBuf,
+    missing: MissingFixture,
+}
+
+impl ReplayTransport {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            missing: MissingFixture::default(),
+        }
+    }
+
+    pub fn missing(mut self, missing: MissingFixture) -> Self {
+        self.missing = missing;
+        self
+    }
+
+    fn replay(&self, request: &HttpRequest) -> Result<HttpResponse, BoxError> {
+        let method = request.method.to_string();
+        let url = redact_query(&request.url);
+        let path = fixture_path(&self.dir, &method, &url);
+        let recording = fs::read(&path)
+            .ok()
+            .map(|bytes| serde_json::from_slice::<Recording>(&bytes))
+            .transpose()?
+            .filter(|recording| recording.method == method && recording.url == url);
+
+        match (recording, self.missing) {
+            (Some(recording), _) => recording.to_response(),
+            (None, missing) => {
+                let error = UnrecordedRequest { method, url, path };
+                match missing {
+                    MissingFixture::Error => Err(Box::new(error)),
+                    MissingFixture::Panic => panic!("{}", error),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, BoxError> {
+        self.replay(&request)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<Result<HttpResponse, BoxError>> {
+        let response = self.replay(&request);
+        Box::pin(async move { response })
+    }
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use super::*;
+    use crate::{
+        error::Error,
+        testing::{self, MATCH_ID, PUUID, SUMMONER_ID},
+        transport::ReqwestTransport,
+        Platform, Region, RiotClient,
+    };
+
+    #[test]
+    fn test_redact_query() {
+        assert_eq!(redact_query("https://x/a?api_key=RGAPI-1"), "https://x/a");
+        assert_eq!(
+            redact_query("https://x/a?start=0&api_key=RGAPI-1&count=5"),
+            "https://x/a?start=0&count=5"
+        );
+        assert_eq!(redact_query("https://x/a"), "https://x/a");
+    }
+
+    #[test]
+    fn test_fixture_path() {
+        let path = fixture_path(
+            Path::new("fixtures"),
+            "GET",
+            "https://americas.api.riotgames.com/lol/match/v5/matches/NA1_1/timeline",
+        );
+        let name = path.file_name().unwrap().to_str().unwrap();
+        assert!(
+            name.starts_with("GET_americas.api.riotgames.com_lol_match_v5_matches_NA1_1_timeline-")
+        );
+        assert_ne!(
+            path,
+            fixture_path(
+                Path::new("fixtures"),
+                "GET",
+                "https://americas.api.riotgames.com/lol/match/v5/matches/NA1_1_timeline",
+            )
+        );
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut server = mockito::Server::new();
+        let summoner = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", PUUID).as_str(),
+            )
+            .with_header("X-App-Rate-Limit", "20:1,100:120")
+            .with_body(testing::fixture("summoner_v4/summoner.json"))
+            .expect(1)
+            .create();
+        let info = server
+            .mock(
+                "GET",
+                format!("/lol/match/v5/matches/{}", MATCH_ID).as_str(),
+            )
+            .with_body(testing::fixture("match_v5/match.json"))
+            .expect(1)
+            .create();
+        let timeline = server
+            .mock(
+                "GET",
+                format!("/lol/match/v5/matches/{}/timeline", MATCH_ID).as_str(),
+            )
+            .with_body(testing::fixture("match_v5/timeline.json"))
+            .expect(1)
+            .create();
+
+        let key = "RGAPI-00000000-1111-2222-3333-444444444444";
+        let recorder = RecordingTransport::new(ReqwestTransport::default(), dir.path());
+        let client = RiotClient::builder(key)
+            .base_url(&server.url())
+            .transport(recorder)
+            .build()
+            .unwrap();
+        let recorded_summoner = client
+            .summoner_v4(Platform::NA1)
+            .by_puuid(&testing::puuid())
+            .unwrap();
+        let recorded_info = client
+            .match_v5(Region::AMERICAS)
+            .match_info(&MATCH_ID.parse().unwrap())
+            .unwrap();
+        let recorded_timeline = client
+            .match_v5(Region::AMERICAS)
+            .match_timeline(&MATCH_ID.parse().unwrap())
+            .unwrap();
+
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 3);
+        for entry in fs::read_dir(dir.path()).unwrap() {
+            let contents = fs::read_to_string(entry.unwrap().path()).unwrap();
+            assert!(!contents.contains(key));
+        }
+
+        let client = RiotClient::builder(key)
+            .base_url(&server.url())
+            .transport(ReplayTransport::new(dir.path()).missing(MissingFixture::Error))
+            .build()
+            .unwrap();
+        assert_eq!(
+            client
+                .summoner_v4(Platform::NA1)
+                .by_puuid(&testing::puuid())
+                .unwrap(),
+            recorded_summoner
+        );
+        assert_eq!(
+            client
+                .match_v5(Region::AMERICAS)
+                .match_info(&MATCH_ID.parse().unwrap())
+                .unwrap(),
+            recorded_info
+        );
+        assert_eq!(
+            client
+                .match_v5(Region::AMERICAS)
+                .match_timeline(&MATCH_ID.parse().unwrap())
+                .unwrap(),
+            recorded_timeline
+        );
+        assert_eq!(
+            client
+                .rate_limiter()
+                .wait_time(Platform::NA1.into(), "summoner-v4.getByPUUID"),
+            None
+        );
+
+        let unrecorded = SUMMONER_ID.parse().unwrap();
+        match client
+            .summoner_v4(Platform::NA1)
+            .by_summoner_id(&unrecorded)
+        {
+            Err(Error::Transport { source, .. }) => {
+                let error = source.downcast_ref::<UnrecordedRequest>().unwrap();
+                assert_eq!(error.method, "GET");
+                assert!(error.url.ends_with(SUMMONER_ID));
+            }
+            other => panic!("expected unrecorded request, got {:?}", other),
+        }
+
+        summoner.assert();
+        info.assert();
+        timeline.assert();
+    }
+
+    #[test]
+    #[should_panic(expected = "no recording for GET")]
+    fn test_replay_panics_on_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = RiotClient::builder("key")
+            .transport(ReplayTransport::new(dir.path()).missing(MissingFixture::Panic))
+            .build()
+            .unwrap();
+        let _ = client
+            .summoner_v4(Platform::NA1)
+            .by_puuid(&testing::puuid());
+    }
+}
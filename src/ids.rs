@@ -0,0 +1,440 @@
+use std::{error::Error as StdError, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Platform, Region};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError {
+    kind: &'static str,
+    value: String,
+    reason: &'static str,
+}
+
+impl ParseIdError {
+    fn new(kind: &'static str, value: &str, reason: &'static str) -> Self {
+        Self {
+            kind,
+            value: value.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} `{}`: {}", self.kind, self.value, self.reason)
+    }
+}
+
+impl StdError for ParseIdError {}
+
+/// Defines a newtype for one of Riot's encrypted ids. `FromStr` and `new`
+/// check the length and alphabet, while deserializing accepts whatever Riot
+/// sends, e.g. the `BOT` puuid of bot participants in match data.
+macro_rules! encrypted_id {
+    ($(#[$attr:meta])* $name:ident, $kind:literal, $min:literal..=$max:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new(id: &str) -> Result<Self, ParseIdError> {
+                let error = |reason| ParseIdError::new($kind, id, reason);
+                if !($min..=$max).contains(&id.len()) {
+                    return Err(error(if $min == $max {
+                        concat!("expected ", $max, " characters")
+                    } else {
+                        concat!("expected ", $min, " to ", $max, " characters")
+                    }));
+                }
+                if !id
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+                {
+                    return Err(error("expected only letters, digits, `-` and `_`"));
+                }
+                Ok(Self(id.to_string()))
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = ParseIdError;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+encrypted_id!(
+    /// A player's globally unique id, the same on every platform and game.
+    Puuid,
+    "puuid",
+    78..=78
+);
+
+encrypted_id!(
+    /// An encrypted summoner id, only meaningful on the platform it came from.
+    SummonerId,
+    "summoner id",
+    1..=63
+);
+
+encrypted_id!(
+    /// An encrypted account id, only meaningful on the platform it came from.
+    AccountId,
+    "account id",
+    1..=56
+);
+
+/// A match id such as `NA1_4123456789`: the platform the game was played on
+/// and its numeric game id. Serialized as the plain string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MatchId {
+    platform: Platform,
+    game_id: u64,
+}
+
+impl MatchId {
+    pub fn new(platform: Platform, game_id: u64) -> Self {
+        Self { platform, game_id }
+    }
+
+    pub fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    pub fn game_id(&self) -> u64 {
+        self.game_id
+    }
+
+    /// The regional route match endpoints must be called on for this match.
+    /// Match views send lookups for this id here, whatever region they were
+    /// created for.
+    pub fn region(&self) -> Region {
+        self.platform.region()
+    }
+}
+
+impl fmt::Display for MatchId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.platform, self.game_id)
+    }
+}
+
+impl FromStr for MatchId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason| ParseIdError::new("match id", s, reason);
+        let (_, game_id) = s
+            .split_once('_')
+            .ok_or_else(|| error("expected `PLATFORM_GAMEID`"))?;
+        let platform =
+            Platform::from_match_id(s).ok_or_else(|| error("unknown platform prefix"))?;
+        if game_id.is_empty() || !game_id.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(error("game id is not a number"));
+        }
+        let game_id = game_id
+            .parse()
+            .map_err(|_| error("game id is out of range"))?;
+        Ok(Self { platform, game_id })
+    }
+}
+
+impl TryFrom<&str> for MatchId {
+    type Error = ParseIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for MatchId {
+    type Error = ParseIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<MatchId> for String {
+    fn from(id: MatchId) -> Self {
+        id.to_string()
+    }
+}
+
+/// The ids returned by a match history lookup, newest first. Ids with a
+/// platform prefix this crate doesn't know yet, e.g. from a new shard, are
+/// kept as strings in `unrecognized` rather than failing the whole list.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "Vec<String>")]
+pub struct MatchIds {
+    pub ids: Vec<MatchId>,
+    pub unrecognized: Vec<String>,
+}
+
+impl MatchIds {
+    /// How many ids Riot returned, recognized or not, for paging with `start`
+    /// and `count`.
+    pub fn len(&self) -> usize {
+        self.ids.len() + self.unrecognized.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl From<Vec<String>> for MatchIds {
+    fn from(values: Vec<String>) -> Self {
+        let mut ids = Self::default();
+        for value in values {
+            match value.parse() {
+                Ok(id) => ids.ids.push(id),
+                Err(_) => ids.unrecognized.push(value),
+            }
+        }
+        ids
+    }
+}
+
+impl IntoIterator for MatchIds {
+    type Item = MatchId;
+    type IntoIter = std::vec::IntoIter<MatchId>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ids.into_iter()
+    }
+}
+
+/// A Riot ID, written `gameName#tagLine`, e.g. `Fixture Summoner#NA1`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RiotId {
+    pub game_name: String,
+    pub tag_line: String,
+}
+
+impl RiotId {
+    pub fn new(game_name: &str, tag_line: &str) -> Self {
+        Self {
+            game_name: game_name.to_string(),
+            tag_line: tag_line.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for RiotId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}", self.game_name, self.tag_line)
+    }
+}
+
+impl FromStr for RiotId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason| ParseIdError::new("riot id", s, reason);
+        let (game_name, tag_line) = s
+            .split_once('#')
+            .ok_or_else(|| error("expected `gameName#tagLine`"))?;
+        if game_name.trim().is_empty() {
+            return Err(error("game name is empty"));
+        }
+        if tag_line.is_empty() || tag_line.contains('#') {
+            return Err(error("expected a single non-empty tag line"));
+        }
+        Ok(Self::new(game_name, tag_line))
+    }
+}
+
+impl TryFrom<&str> for RiotId {
+    type Error = ParseIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for RiotId {
+    type Error = ParseIdError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<RiotId> for String {
+    fn from(id: RiotId) -> Self {
+        id.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{ACCOUNT_ID, PUUID, SUMMONER_ID};
+
+    #[test]
+    fn test_match_id() {
+        let id: MatchId = "NA1_4123456789".parse().unwrap();
+        assert_eq!(id.platform(), Platform::NA1);
+        assert_eq!(id.game_id(), 4123456789);
+        assert_eq!(id.region(), Region::AMERICAS);
+        assert_eq!(id.to_string(), "NA1_4123456789");
+        assert_eq!(
+            MatchId::try_from("vn2_1").unwrap(),
+            MatchId::new(Platform::VN2, 1)
+        );
+        assert_eq!(MatchId::new(Platform::VN2, 1).region(), Region::SEA);
+
+        for (value, reason) in [
+            ("4123456789", "expected `PLATFORM_GAMEID`"),
+            ("XX1_4123456789", "unknown platform prefix"),
+            ("NA1_", "game id is not a number"),
+            ("NA1_-1", "game id is not a number"),
+            ("NA1_99999999999999999999", "game id is out of range"),
+        ] {
+            let error = value.parse::<MatchId>().unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("invalid match id `{}`: {}", value, reason)
+            );
+        }
+    }
+
+    #[test]
+    fn test_match_ids_keep_unknown_platforms() {
+        let ids: MatchIds = serde_json::from_str(r#"["NA1_4123456789","ZZ9_1","EUW1_2"]"#).unwrap();
+        assert_eq!(
+            ids.ids,
+            [
+                MatchId::new(Platform::NA1, 4123456789),
+                MatchId::new(Platform::EUW1, 2)
+            ]
+        );
+        assert_eq!(ids.unrecognized, ["ZZ9_1"]);
+        assert_eq!(ids.len(), 3);
+    }
+
+    #[test]
+    fn test_encrypted_ids() {
+        let puuid: Puuid = PUUID.parse().unwrap();
+        assert_eq!(puuid, PUUID);
+        assert_eq!(puuid.to_string(), PUUID);
+        assert_eq!(SummonerId::new(SUMMONER_ID).unwrap().as_str(), SUMMONER_ID);
+        assert_eq!(AccountId::try_from(ACCOUNT_ID).unwrap(), ACCOUNT_ID);
+
+        assert_eq!(
+            Puuid::new(SUMMONER_ID).unwrap_err().to_string(),
+            format!("invalid puuid `{}`: expected 78 characters", SUMMONER_ID)
+        );
+        assert_eq!(
+            SummonerId::new("").unwrap_err().to_string(),
+            "invalid summoner id ``: expected 1 to 63 characters"
+        );
+        assert!(AccountId::new(PUUID).is_err());
+        assert_eq!(
+            SummonerId::new("abc/def").unwrap_err().to_string(),
+            "invalid summoner id `abc/def`: expected only letters, digits, `-` and `_`"
+        );
+    }
+
+    #[test]
+    fn test_encrypted_id_serde() {
+        let puuid: Puuid = serde_json::from_str(&format!("\"{}\"", PUUID)).unwrap();
+        assert_eq!(
+            serde_json::to_string(&puuid).unwrap(),
+            format!("\"{}\"", PUUID)
+        );
+        let bot: Puuid = serde_json::from_str("\"BOT\"").unwrap();
+        assert_eq!(bot, "BOT");
+    }
+
+    #[test]
+    fn test_riot_id() {
+        let id: RiotId = "Fixture Summoner#NA1".parse().unwrap();
+        assert_eq!(id, RiotId::new("Fixture Summoner", "NA1"));
+        assert_eq!(id.to_string(), "Fixture Summoner#NA1");
+        assert_eq!(
+            serde_json::to_string(&id).unwrap(),
+            r#""Fixture Summoner#NA1""#
+        );
+
+        for (value, reason) in [
+            ("Fixture Summoner", "expected `gameName#tagLine`"),
+            ("#NA1", "game name is empty"),
+            ("Fixture#", "expected a single non-empty tag line"),
+            ("Fixture#NA#1", "expected a single non-empty tag line"),
+        ] {
+            let error = value.parse::<RiotId>().unwrap_err();
+            assert_eq!(
+                error.to_string(),
+                format!("invalid riot id `{}`: {}", value, reason)
+            );
+        }
+    }
+
+    #[test]
+    fn test_match_id_serde() {
+        let ids: Vec<MatchId> = serde_json::from_str(r#"["EUW1_6543210", "KR_7000"]"#).unwrap();
+        assert_eq!(
+            ids,
+            [
+                MatchId::new(Platform::EUW1, 6543210),
+                MatchId::new(Platform::KR, 7000)
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&ids).unwrap(),
+            r#"["EUW1_6543210","KR_7000"]"#
+        );
+        assert!(serde_json::from_str::<MatchId>(r#""EUW1""#).is_err());
+    }
+}
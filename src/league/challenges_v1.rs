@@ -0,0 +1,390 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{query_string, ApiRequest, Executor},
+    ids::Puuid,
+    Route,
+};
+pub use crate::{error::Error, Platform};
+
+const ENDPOINT: &str = "/lol/challenges/v1";
+
+/// A challenge crystal, lowest first.
+///
+/// Thresholds can also be keyed by the `HIGHEST*`/`LOWEST` markers, which
+/// come after every real level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChallengeLevel {
+    None,
+    Iron,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Diamond,
+    Master,
+    Grandmaster,
+    Challenger,
+    HighestNotLeaderboardOnly,
+    Highest,
+    Lowest,
+}
+
+impl ChallengeLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "NONE",
+            Self::Iron => "IRON",
+            Self::Bronze => "BRONZE",
+            Self::Silver => "SILVER",
+            Self::Gold => "GOLD",
+            Self::Platinum => "PLATINUM",
+            Self::Diamond => "DIAMOND",
+            Self::Master => "MASTER",
+            Self::Grandmaster => "GRANDMASTER",
+            Self::Challenger => "CHALLENGER",
+            Self::HighestNotLeaderboardOnly => "HIGHEST_NOT_LEADERBOARD_ONLY",
+            Self::Highest => "HIGHEST",
+            Self::Lowest => "LOWEST",
+        }
+    }
+
+    /// Master, Grandmaster and Challenger, the levels with leaderboards.
+    pub fn is_apex(self) -> bool {
+        matches!(self, Self::Master | Self::Grandmaster | Self::Challenger)
+    }
+}
+
+impl fmt::Display for ChallengeLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChallengeState {
+    Disabled,
+    Hidden,
+    Enabled,
+    Archived,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Tracking {
+    Lifetime,
+    Season,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeConfig {
+    pub id: i64,
+    /// Keyed by locale, e.g. `en_US`.
+    pub localized_names: BTreeMap<String, ChallengeText>,
+    pub state: ChallengeState,
+    pub tracking: Option<Tracking>,
+    /// Milliseconds since the Unix epoch.
+    pub start_timestamp: Option<i64>,
+    pub end_timestamp: Option<i64>,
+    pub leaderboard: bool,
+    /// The value needed to reach each level.
+    pub thresholds: BTreeMap<ChallengeLevel, f64>,
+}
+
+impl ChallengeConfig {
+    pub fn text(&self, locale: &str) -> Option<&ChallengeText> {
+        self.localized_names.get(locale)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeText {
+    pub name: String,
+    pub description: String,
+    pub short_description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApexPlayer {
+    pub puuid: Puuid,
+    pub value: f64,
+    pub position: i64,
+}
+
+/// A player's challenge progress and profile preferences.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerData {
+    pub challenges: Vec<ChallengeProgress>,
+    pub preferences: Preferences,
+    pub total_points: ChallengePoints,
+    /// Keyed by category, e.g. `TEAMWORK`.
+    pub category_points: BTreeMap<String, ChallengePoints>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengeProgress {
+    pub challenge_id: i64,
+    /// Share of players at or above this level, from 0 to 1.
+    pub percentile: f64,
+    pub level: ChallengeLevel,
+    pub value: f64,
+    /// Milliseconds since the Unix epoch.
+    pub achieved_time: Option<i64>,
+    /// Leaderboard position, only for apex levels.
+    pub position: Option<i64>,
+    pub players_in_level: Option<i64>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preferences {
+    pub banner_accent: Option<String>,
+    /// Id of the title shown on the player's profile.
+    pub title: Option<String>,
+    /// Challenges pinned to the profile.
+    #[serde(default)]
+    pub challenge_ids: Vec<i64>,
+    pub crest_border: Option<String>,
+    pub prestige_crest_border_level: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChallengePoints {
+    pub level: ChallengeLevel,
+    pub current: i64,
+    pub max: i64,
+    pub percentile: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChallengesV1<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl ChallengesV1<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).challenges_v1(platform)
+    }
+}
+
+impl<C: Executor> ChallengesV1<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn configs(&self) -> C::Output<Vec<ChallengeConfig>> {
+        let path = format!("{}/challenges/config", ENDPOINT);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "challenges-v1.getAllChallengeConfigs",
+            path,
+        ))
+    }
+
+    pub fn config(&self, challenge_id: i64) -> C::Output<ChallengeConfig> {
+        let path = format!("{}/challenges/{}/config", ENDPOINT, challenge_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "challenges-v1.getChallengeConfigs",
+            path,
+        ))
+    }
+
+    /// The share of players at each level, for every challenge.
+    pub fn percentiles(&self) -> C::Output<BTreeMap<i64, BTreeMap<ChallengeLevel, f64>>> {
+        let path = format!("{}/challenges/percentiles", ENDPOINT);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "challenges-v1.getAllChallengePercentiles",
+            path,
+        ))
+    }
+
+    pub fn percentiles_by_id(&self, challenge_id: i64) -> C::Output<BTreeMap<ChallengeLevel, f64>> {
+        let path = format!("{}/challenges/{}/percentiles", ENDPOINT, challenge_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "challenges-v1.getChallengePercentiles",
+            path,
+        ))
+    }
+
+    /// The top players at an apex `level`; see [`ChallengeLevel::is_apex`].
+    pub fn leaderboard(
+        &self,
+        challenge_id: i64,
+        level: ChallengeLevel,
+        limit: Option<u32>,
+    ) -> C::Output<Vec<ApexPlayer>> {
+        let path = format!(
+            "{}/challenges/{}/leaderboards/by-level/{}{}",
+            ENDPOINT,
+            challenge_id,
+            level,
+            query_string(&[("limit", limit.map(|l| l.to_string()))])
+        );
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "challenges-v1.getChallengeLeaderboards",
+            path,
+        ))
+    }
+
+    pub fn player_data(&self, puuid: &Puuid) -> C::Output<PlayerData> {
+        let path = format!("{}/player-data/{}", ENDPOINT, puuid);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "challenges-v1.getPlayerData",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder, PUUID};
+
+    #[test]
+    fn test_requests() {
+        let api = ChallengesV1::with_client(Recorder, Platform::NA1);
+        testing::assert_requests(&[
+            (
+                api.configs(),
+                testing::get(
+                    Platform::NA1,
+                    "challenges-v1.getAllChallengeConfigs",
+                    format!("{}/challenges/config", ENDPOINT),
+                ),
+            ),
+            (
+                api.config(101101),
+                testing::get(
+                    Platform::NA1,
+                    "challenges-v1.getChallengeConfigs",
+                    format!("{}/challenges/101101/config", ENDPOINT),
+                ),
+            ),
+            (
+                api.percentiles(),
+                testing::get(
+                    Platform::NA1,
+                    "challenges-v1.getAllChallengePercentiles",
+                    format!("{}/challenges/percentiles", ENDPOINT),
+                ),
+            ),
+            (
+                api.percentiles_by_id(101101),
+                testing::get(
+                    Platform::NA1,
+                    "challenges-v1.getChallengePercentiles",
+                    format!("{}/challenges/101101/percentiles", ENDPOINT),
+                ),
+            ),
+            (
+                api.leaderboard(101101, ChallengeLevel::Challenger, Some(2)),
+                testing::get(
+                    Platform::NA1,
+                    "challenges-v1.getChallengeLeaderboards",
+                    format!(
+                        "{}/challenges/101101/leaderboards/by-level/CHALLENGER?limit=2",
+                        ENDPOINT
+                    ),
+                ),
+            ),
+            (
+                api.leaderboard(101101, ChallengeLevel::Master, None),
+                testing::get(
+                    Platform::NA1,
+                    "challenges-v1.getChallengeLeaderboards",
+                    format!(
+                        "{}/challenges/101101/leaderboards/by-level/MASTER",
+                        ENDPOINT
+                    ),
+                ),
+            ),
+            (
+                api.player_data(&testing::puuid()),
+                testing::get(
+                    Platform::NA1,
+                    "challenges-v1.getPlayerData",
+                    format!("{}/player-data/{}", ENDPOINT, PUUID),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_decode_configs() {
+        let res: Vec<ChallengeConfig> = testing::decode("challenges_v1/configs.json");
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].tracking, None);
+        assert_eq!(res[1].state, ChallengeState::Enabled);
+        assert_eq!(res[1].tracking, Some(Tracking::Lifetime));
+        assert_eq!(res[1].text("de_DE").unwrap().name, "DPS-Bedrohung");
+        assert_eq!(res[1].thresholds[&ChallengeLevel::Master], 50.0);
+    }
+
+    #[test]
+    fn test_decode_percentiles() {
+        let res: BTreeMap<i64, BTreeMap<ChallengeLevel, f64>> =
+            testing::decode("challenges_v1/percentiles.json");
+        assert_eq!(res[&101101][&ChallengeLevel::Gold], 0.0724);
+        assert_eq!(res[&0].len(), 10);
+    }
+
+    #[test]
+    fn test_decode_leaderboard() {
+        let res: Vec<ApexPlayer> = testing::decode("challenges_v1/leaderboard.json");
+        assert_eq!(res[0].puuid, PUUID);
+        assert_eq!(res[1].position, 2);
+    }
+
+    #[test]
+    fn test_decode_player_data() {
+        let res: PlayerData = testing::decode("challenges_v1/player_data.json");
+        assert_eq!(res.total_points.level, ChallengeLevel::Diamond);
+        assert_eq!(res.category_points["TEAMWORK"].current, 3870);
+        assert_eq!(res.preferences.title.as_deref(), Some("10120402"));
+        assert_eq!(res.challenges[1].position, Some(1852));
+        assert_eq!(res.challenges[2].level, ChallengeLevel::None);
+        assert_eq!(res.challenges[2].achieved_time, None);
+    }
+
+    #[test]
+    fn test_levels() {
+        assert!(ChallengeLevel::Challenger > ChallengeLevel::Diamond);
+        assert!(ChallengeLevel::Iron > ChallengeLevel::None);
+        assert!(ChallengeLevel::Grandmaster.is_apex());
+        assert!(!ChallengeLevel::Highest.is_apex());
+        assert_eq!(
+            ChallengeLevel::HighestNotLeaderboardOnly.to_string(),
+            "HIGHEST_NOT_LEADERBOARD_ONLY"
+        );
+    }
+}
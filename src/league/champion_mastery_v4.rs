@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{query_string, ApiRequest, Executor},
+    ids::Puuid,
+    Route,
+};
+pub use crate::{error::Error, Platform};
+
+const ENDPOINT: &str = "/lol/champion-mastery/v4";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionMastery {
+    pub puuid: Puuid,
+    /// The same id as `match_v5::Participant::champion_id`.
+    pub champion_id: i64,
+    pub champion_level: i64,
+    pub champion_points: i64,
+    /// Milliseconds since the Unix epoch.
+    pub last_play_time: i64,
+    pub champion_points_since_last_level: i64,
+    pub champion_points_until_next_level: i64,
+    #[serde(default)]
+    pub chest_granted: bool,
+    #[serde(default)]
+    pub tokens_earned: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChampionMasteryV4<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl ChampionMasteryV4<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).champion_mastery_v4(platform)
+    }
+}
+
+impl<C: Executor> ChampionMasteryV4<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Every champion the player has mastery on, highest points first.
+    pub fn by_puuid(&self, puuid: &Puuid) -> C::Output<Vec<ChampionMastery>> {
+        let path = format!("{}/champion-masteries/by-puuid/{}", ENDPOINT, puuid);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "champion-mastery-v4.getAllChampionMasteriesByPUUID",
+            path,
+        ))
+    }
+
+    pub fn by_champion(&self, puuid: &Puuid, champion_id: i64) -> C::Output<ChampionMastery> {
+        let path = format!(
+            "{}/champion-masteries/by-puuid/{}/by-champion/{}",
+            ENDPOINT, puuid, champion_id
+        );
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "champion-mastery-v4.getChampionMasteryByPUUID",
+            path,
+        ))
+    }
+
+    /// The player's `count` best champions; Riot defaults to 3 when `None`.
+    pub fn top(&self, puuid: &Puuid, count: Option<u32>) -> C::Output<Vec<ChampionMastery>> {
+        let path = format!(
+            "{}/champion-masteries/by-puuid/{}/top{}",
+            ENDPOINT,
+            puuid,
+            query_string(&[("count", count.map(|c| c.to_string()))])
+        );
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "champion-mastery-v4.getTopChampionMasteriesByPUUID",
+            path,
+        ))
+    }
+
+    /// The sum of the player's champion mastery levels.
+    pub fn score(&self, puuid: &Puuid) -> C::Output<i64> {
+        let path = format!("{}/scores/by-puuid/{}", ENDPOINT, puuid);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "champion-mastery-v4.getChampionMasteryScoreByPUUID",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder, PUUID};
+
+    #[test]
+    fn test_requests() {
+        let api = ChampionMasteryV4::with_client(Recorder, Platform::NA1);
+        let puuid = testing::puuid();
+        testing::assert_requests(&[
+            (
+                api.by_puuid(&puuid),
+                testing::get(
+                    Platform::NA1,
+                    "champion-mastery-v4.getAllChampionMasteriesByPUUID",
+                    format!("{}/champion-masteries/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.by_champion(&puuid, 157),
+                testing::get(
+                    Platform::NA1,
+                    "champion-mastery-v4.getChampionMasteryByPUUID",
+                    format!(
+                        "{}/champion-masteries/by-puuid/{}/by-champion/157",
+                        ENDPOINT, PUUID
+                    ),
+                ),
+            ),
+            (
+                api.top(&puuid, Some(3)),
+                testing::get(
+                    Platform::NA1,
+                    "champion-mastery-v4.getTopChampionMasteriesByPUUID",
+                    format!(
+                        "{}/champion-masteries/by-puuid/{}/top?count=3",
+                        ENDPOINT, PUUID
+                    ),
+                ),
+            ),
+            (
+                api.top(&puuid, None),
+                testing::get(
+                    Platform::NA1,
+                    "champion-mastery-v4.getTopChampionMasteriesByPUUID",
+                    format!("{}/champion-masteries/by-puuid/{}/top", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.score(&puuid),
+                testing::get(
+                    Platform::NA1,
+                    "champion-mastery-v4.getChampionMasteryScoreByPUUID",
+                    format!("{}/scores/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_decode() {
+        let res: Vec<ChampionMastery> = testing::decode("champion_mastery_v4/masteries.json");
+        assert_eq!(res.len(), 3);
+        assert_eq!(res[0].champion_id, 103);
+        assert_eq!(res[0].champion_level, 7);
+        assert!(res[0].chest_granted);
+        assert_eq!(res[1].champion_points, 118204);
+        assert_eq!(res[1].tokens_earned, 2);
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    Route,
+};
+pub use crate::{error::Error, Platform};
+
+const ENDPOINT: &str = "/lol/platform/v3";
+
+/// The week's free champion rotation.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionInfo {
+    pub free_champion_ids: Vec<i64>,
+    /// The smaller rotation offered to accounts up to `max_new_player_level`.
+    pub free_champion_ids_for_new_players: Vec<i64>,
+    pub max_new_player_level: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChampionV3<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl ChampionV3<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).champion_v3(platform)
+    }
+}
+
+impl<C: Executor> ChampionV3<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn rotations(&self) -> C::Output<ChampionInfo> {
+        let path = format!("{}/champion-rotations", ENDPOINT);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "champion-v3.getChampionInfo",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder};
+
+    #[test]
+    fn test_requests() {
+        let api = ChampionV3::with_client(Recorder, Platform::EUN1);
+        testing::assert_requests(&[(
+            api.rotations(),
+            testing::get(
+                Platform::EUN1,
+                "champion-v3.getChampionInfo",
+                format!("{}/champion-rotations", ENDPOINT),
+            ),
+        )]);
+    }
+
+    #[test]
+    fn test_decode() {
+        let res: ChampionInfo = testing::decode("champion_v3/rotations.json");
+        assert_eq!(res.free_champion_ids.len(), 20);
+        assert!(res.free_champion_ids.contains(&157));
+        assert_eq!(res.free_champion_ids_for_new_players[0], 222);
+        assert_eq!(res.max_new_player_level, 10);
+    }
+}
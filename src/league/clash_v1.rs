@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    ids::{Puuid, SummonerId},
+    Route,
+};
+pub use crate::{error::Error, Platform};
+
+const ENDPOINT: &str = "/lol/clash/v1";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Position {
+    #[default]
+    Unselected,
+    Fill,
+    Top,
+    Jungle,
+    Middle,
+    Bottom,
+    Utility,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Role {
+    Captain,
+    #[default]
+    Member,
+}
+
+/// A player's registration, on a team or still looking for one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Player {
+    pub summoner_id: SummonerId,
+    pub puuid: Option<Puuid>,
+    pub team_id: Option<String>,
+    pub position: Position,
+    pub role: Role,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    pub id: String,
+    pub tournament_id: i64,
+    pub name: String,
+    pub icon_id: i64,
+    pub tier: i64,
+    /// The captain's summoner id.
+    pub captain: SummonerId,
+    pub abbreviation: String,
+    pub players: Vec<Player>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tournament {
+    pub id: i64,
+    pub theme_id: i64,
+    pub name_key: String,
+    pub name_key_secondary: String,
+    pub schedule: Vec<TournamentPhase>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentPhase {
+    pub id: i64,
+    /// Milliseconds since the Unix epoch.
+    pub registration_time: i64,
+    /// Milliseconds since the Unix epoch.
+    pub start_time: i64,
+    pub cancelled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ClashV1<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl ClashV1<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).clash_v1(platform)
+    }
+}
+
+impl<C: Executor> ClashV1<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// The player's active registrations, one per upcoming tournament.
+    pub fn players_by_puuid(&self, puuid: &Puuid) -> C::Output<Vec<Player>> {
+        let path = format!("{}/players/by-puuid/{}", ENDPOINT, puuid);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "clash-v1.getPlayersByPUUID",
+            path,
+        ))
+    }
+
+    pub fn players_by_summoner(&self, summoner_id: &SummonerId) -> C::Output<Vec<Player>> {
+        let path = format!("{}/players/by-summoner/{}", ENDPOINT, summoner_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "clash-v1.getPlayersBySummoner",
+            path,
+        ))
+    }
+
+    pub fn team(&self, team_id: &str) -> C::Output<Team> {
+        let path = format!("{}/teams/{}", ENDPOINT, team_id);
+        self.client
+            .execute(ApiRequest::new(self.route, "clash-v1.getTeamById", path))
+    }
+
+    /// Active and upcoming tournaments.
+    pub fn tournaments(&self) -> C::Output<Vec<Tournament>> {
+        let path = format!("{}/tournaments", ENDPOINT);
+        self.client
+            .execute(ApiRequest::new(self.route, "clash-v1.getTournaments", path))
+    }
+
+    pub fn tournament_by_team(&self, team_id: &str) -> C::Output<Tournament> {
+        let path = format!("{}/tournaments/by-team/{}", ENDPOINT, team_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "clash-v1.getTournamentByTeam",
+            path,
+        ))
+    }
+
+    pub fn tournament(&self, tournament_id: i64) -> C::Output<Tournament> {
+        let path = format!("{}/tournaments/{}", ENDPOINT, tournament_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "clash-v1.getTournamentById",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder, PUUID, SUMMONER_ID};
+
+    const TEAM_ID: &str = "7c2e9f14-0b3a-4d8e-a6c1-52f7e9d0b384";
+
+    #[test]
+    fn test_requests() {
+        let api = ClashV1::with_client(Recorder, Platform::NA1);
+        testing::assert_requests(&[
+            (
+                api.players_by_puuid(&testing::puuid()),
+                testing::get(
+                    Platform::NA1,
+                    "clash-v1.getPlayersByPUUID",
+                    format!("{}/players/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.players_by_summoner(&SUMMONER_ID.parse().unwrap()),
+                testing::get(
+                    Platform::NA1,
+                    "clash-v1.getPlayersBySummoner",
+                    format!("{}/players/by-summoner/{}", ENDPOINT, SUMMONER_ID),
+                ),
+            ),
+            (
+                api.team(TEAM_ID),
+                testing::get(
+                    Platform::NA1,
+                    "clash-v1.getTeamById",
+                    format!("{}/teams/{}", ENDPOINT, TEAM_ID),
+                ),
+            ),
+            (
+                api.tournaments(),
+                testing::get(
+                    Platform::NA1,
+                    "clash-v1.getTournaments",
+                    format!("{}/tournaments", ENDPOINT),
+                ),
+            ),
+            (
+                api.tournament_by_team(TEAM_ID),
+                testing::get(
+                    Platform::NA1,
+                    "clash-v1.getTournamentByTeam",
+                    format!("{}/tournaments/by-team/{}", ENDPOINT, TEAM_ID),
+                ),
+            ),
+            (
+                api.tournament(4103),
+                testing::get(
+                    Platform::NA1,
+                    "clash-v1.getTournamentById",
+                    format!("{}/tournaments/4103", ENDPOINT),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_decode_players() {
+        let res: Vec<Player> = testing::decode("clash_v1/players.json");
+        assert_eq!(res[0].summoner_id, SUMMONER_ID);
+        assert_eq!(res[0].position, Position::Jungle);
+        assert_eq!(res[0].role, Role::Captain);
+        assert_eq!(res[0].team_id.as_deref(), Some(TEAM_ID));
+    }
+
+    #[test]
+    fn test_decode_team() {
+        let res: Team = testing::decode("clash_v1/team.json");
+        assert_eq!(res.abbreviation, "FIX");
+        assert_eq!(res.captain, SUMMONER_ID);
+        assert_eq!(res.players.len(), 3);
+        assert_eq!(res.players[1].position, Position::Utility);
+        assert_eq!(res.players[1].puuid, None);
+    }
+
+    #[test]
+    fn test_decode_tournaments() {
+        let res: Vec<Tournament> = testing::decode("clash_v1/tournaments.json");
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].id, 4102);
+        assert_eq!(res[0].name_key_secondary, "day_2");
+        assert!(res[1].schedule[0].cancelled);
+    }
+}
@@ -0,0 +1,252 @@
+#[cfg(feature = "async")]
+use crate::AsyncRiotClient;
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    Route,
+};
+pub use crate::{
+    error::Error,
+    league::league_v4::{Division, LeagueEntry, RankedQueue, Tier},
+    Platform,
+};
+
+const ENDPOINT: &str = "/lol/league-exp/v4";
+
+/// Like [`LeagueV4::entries`](crate::league::league_v4::LeagueV4::entries),
+/// but also pages through the apex tiers.
+#[derive(Debug, Clone)]
+pub struct LeagueExpV4<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl LeagueExpV4<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).league_exp_v4(platform)
+    }
+}
+
+impl<C: Executor> LeagueExpV4<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn entries(
+        &self,
+        queue: RankedQueue,
+        tier: Tier,
+        division: Division,
+        page: u32,
+    ) -> C::Output<Vec<LeagueEntry>> {
+        let path = format!(
+            "{}/entries/{}/{}/{}?page={}",
+            ENDPOINT, queue, tier, division, page
+        );
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "league-exp-v4.getLeagueEntries",
+            path,
+        ))
+    }
+
+    /// Walks every page of a division from page 1, stopping after the first
+    /// empty page or error. Apex tiers only have [`Division::I`].
+    pub fn pages(&self, queue: RankedQueue, tier: Tier, division: Division) -> EntryPages<C> {
+        EntryPages {
+            api: self.clone(),
+            queue,
+            tier,
+            division,
+            page: 1,
+            done: false,
+        }
+    }
+}
+
+/// A cursor over the pages of a division, from [`LeagueExpV4::pages`]. Call
+/// `next_page` on either client; with the blocking client it's also an
+/// `Iterator`.
+#[derive(Debug, Clone)]
+pub struct EntryPages<C> {
+    api: LeagueExpV4<C>,
+    queue: RankedQueue,
+    tier: Tier,
+    division: Division,
+    page: u32,
+    done: bool,
+}
+
+impl<C: Executor> EntryPages<C> {
+    /// The page the next call fetches.
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    fn fetch(&self) -> C::Output<Vec<LeagueEntry>> {
+        self.api
+            .entries(self.queue.clone(), self.tier, self.division, self.page)
+    }
+
+    /// Moves past a fetched page, swallowing the empty one that ends the walk.
+    fn advance(
+        &mut self,
+        res: Result<Vec<LeagueEntry>, Error>,
+    ) -> Option<Result<Vec<LeagueEntry>, Error>> {
+        match &res {
+            Ok(entries) if entries.is_empty() => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => self.page += 1,
+            Err(_) => self.done = true,
+        }
+        Some(res)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl EntryPages<RiotClient> {
+    /// The next non-empty page, or `None` once the walk is over.
+    pub fn next_page(&mut self) -> Option<Result<Vec<LeagueEntry>, Error>> {
+        if self.done {
+            return None;
+        }
+        let res = self.fetch();
+        self.advance(res)
+    }
+}
+
+#[cfg(feature = "async")]
+impl EntryPages<AsyncRiotClient> {
+    /// The next non-empty page, or `None` once the walk is over.
+    pub async fn next_page(&mut self) -> Option<Result<Vec<LeagueEntry>, Error>> {
+        if self.done {
+            return None;
+        }
+        let res = self.fetch().await;
+        self.advance(res)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Iterator for EntryPages<RiotClient> {
+    type Item = Result<Vec<LeagueEntry>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_page()
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl std::iter::FusedIterator for EntryPages<RiotClient> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder, PUUID};
+
+    fn page_path(page: u32) -> String {
+        format!(
+            "{}/entries/RANKED_SOLO_5x5/MASTER/I?page={}",
+            ENDPOINT, page
+        )
+    }
+
+    #[test]
+    fn test_requests() {
+        let api = LeagueExpV4::with_client(Recorder, Platform::KR);
+        testing::assert_requests(&[(
+            api.entries(RankedQueue::Solo, Tier::Master, Division::I, 2),
+            testing::get(Platform::KR, "league-exp-v4.getLeagueEntries", page_path(2)),
+        )]);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_pages() {
+        let mut server = mockito::Server::new();
+        let first = server
+            .mock("GET", page_path(1).as_str())
+            .with_body(testing::fixture("league_exp_v4/master_page1.json"))
+            .create();
+        let second = server
+            .mock("GET", page_path(2).as_str())
+            .with_body(testing::fixture("league_exp_v4/master_page2.json"))
+            .create();
+        let last = server
+            .mock("GET", page_path(3).as_str())
+            .with_body("[]")
+            .create();
+
+        let api = testing::client(&server).league_exp_v4(Platform::KR);
+        let pages = api
+            .pages(RankedQueue::Solo, Tier::Master, Division::I)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0][0].puuid.as_ref().unwrap(), PUUID);
+        assert_eq!(pages[0][0].tier, Tier::Master);
+        assert!(pages[1][0].inactive);
+        first.assert();
+        second.assert();
+        last.assert();
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_pages_stop_on_error() {
+        let mut server = mockito::Server::new();
+        let first = server
+            .mock("GET", page_path(1).as_str())
+            .with_body(testing::fixture("league_exp_v4/master_page1.json"))
+            .create();
+        let second = server
+            .mock("GET", page_path(2).as_str())
+            .with_status(403)
+            .create();
+
+        let api = testing::client(&server).league_exp_v4(Platform::KR);
+        let mut pages = api.pages(RankedQueue::Solo, Tier::Master, Division::I);
+        assert_eq!(pages.next().unwrap().unwrap().len(), 2);
+        assert!(pages.next().unwrap().is_err());
+        assert!(pages.next().is_none());
+        first.assert();
+        second.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_pages_async() {
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", page_path(1).as_str())
+            .with_body(testing::fixture("league_exp_v4/master_page1.json"))
+            .create_async()
+            .await;
+        let last = server
+            .mock("GET", page_path(2).as_str())
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let api = testing::async_client(&server).league_exp_v4(Platform::KR);
+        let mut pages = api.pages(RankedQueue::Solo, Tier::Master, Division::I);
+        let page = pages.next_page().await.unwrap().unwrap();
+        assert_eq!(page[0].puuid.as_ref().unwrap(), PUUID);
+        assert_eq!(pages.page(), 2);
+        assert!(pages.next_page().await.is_none());
+        assert!(pages.next_page().await.is_none());
+        first.assert_async().await;
+        last.assert_async().await;
+    }
+}
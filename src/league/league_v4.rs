@@ -0,0 +1,392 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    ids::{Puuid, SummonerId},
+    Route,
+};
+pub use crate::{error::Error, Platform};
+
+const ENDPOINT: &str = "/lol/league/v4";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Tier {
+    Iron,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Emerald,
+    Diamond,
+    Master,
+    Grandmaster,
+    Challenger,
+}
+
+impl Tier {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Iron => "IRON",
+            Self::Bronze => "BRONZE",
+            Self::Silver => "SILVER",
+            Self::Gold => "GOLD",
+            Self::Platinum => "PLATINUM",
+            Self::Emerald => "EMERALD",
+            Self::Diamond => "DIAMOND",
+            Self::Master => "MASTER",
+            Self::Grandmaster => "GRANDMASTER",
+            Self::Challenger => "CHALLENGER",
+        }
+    }
+
+    /// Master and above have a single division and are listed per league
+    /// rather than through paged entries.
+    pub fn is_apex(self) -> bool {
+        self >= Self::Master
+    }
+}
+
+impl fmt::Display for Tier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Declared lowest first, so `Division::I > Division::IV`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Division {
+    IV,
+    III,
+    II,
+    I,
+}
+
+impl Division {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::IV => "IV",
+            Self::III => "III",
+            Self::II => "II",
+            Self::I => "I",
+        }
+    }
+}
+
+impl fmt::Display for Division {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum RankedQueue {
+    Solo,
+    Flex,
+    /// A queue this crate doesn't know about yet, kept as Riot sent it, e.g.
+    /// `RANKED_FLEX_TT`.
+    Other(String),
+}
+
+impl RankedQueue {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Solo => "RANKED_SOLO_5x5",
+            Self::Flex => "RANKED_FLEX_SR",
+            Self::Other(queue) => queue,
+        }
+    }
+}
+
+impl fmt::Display for RankedQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<String> for RankedQueue {
+    fn from(queue: String) -> Self {
+        match queue.as_str() {
+            "RANKED_SOLO_5x5" => Self::Solo,
+            "RANKED_FLEX_SR" => Self::Flex,
+            _ => Self::Other(queue),
+        }
+    }
+}
+
+impl From<RankedQueue> for String {
+    fn from(queue: RankedQueue) -> Self {
+        match queue {
+            RankedQueue::Other(queue) => queue,
+            queue => queue.as_str().to_string(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MiniSeries {
+    pub losses: u32,
+    /// One character per game, e.g. `WLN` for a win, a loss and one unplayed.
+    pub progress: String,
+    pub target: u32,
+    pub wins: u32,
+}
+
+/// A player's standing in one ranked queue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueEntry {
+    pub league_id: String,
+    pub queue_type: RankedQueue,
+    pub tier: Tier,
+    pub rank: Division,
+    pub summoner_id: Option<SummonerId>,
+    pub puuid: Option<Puuid>,
+    pub league_points: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub veteran: bool,
+    pub inactive: bool,
+    pub fresh_blood: bool,
+    pub hot_streak: bool,
+    pub mini_series: Option<MiniSeries>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueList {
+    pub league_id: String,
+    pub tier: Tier,
+    pub queue: RankedQueue,
+    pub name: String,
+    pub entries: Vec<LeagueItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueItem {
+    pub summoner_id: Option<SummonerId>,
+    pub puuid: Option<Puuid>,
+    pub rank: Division,
+    pub league_points: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub veteran: bool,
+    pub inactive: bool,
+    pub fresh_blood: bool,
+    pub hot_streak: bool,
+    pub mini_series: Option<MiniSeries>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LeagueV4<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl LeagueV4<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).league_v4(platform)
+    }
+}
+
+impl<C: Executor> LeagueV4<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn entries_by_summoner(&self, summoner_id: &SummonerId) -> C::Output<Vec<LeagueEntry>> {
+        let path = format!("{}/entries/by-summoner/{}", ENDPOINT, summoner_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "league-v4.getLeagueEntriesForSummoner",
+            path,
+        ))
+    }
+
+    pub fn entries_by_puuid(&self, puuid: &Puuid) -> C::Output<Vec<LeagueEntry>> {
+        let path = format!("{}/entries/by-puuid/{}", ENDPOINT, puuid);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "league-v4.getLeagueEntriesByPUUID",
+            path,
+        ))
+    }
+
+    pub fn challenger(&self, queue: RankedQueue) -> C::Output<LeagueList> {
+        let path = format!("{}/challengerleagues/by-queue/{}", ENDPOINT, queue);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "league-v4.getChallengerLeague",
+            path,
+        ))
+    }
+
+    pub fn grandmaster(&self, queue: RankedQueue) -> C::Output<LeagueList> {
+        let path = format!("{}/grandmasterleagues/by-queue/{}", ENDPOINT, queue);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "league-v4.getGrandmasterLeague",
+            path,
+        ))
+    }
+
+    pub fn master(&self, queue: RankedQueue) -> C::Output<LeagueList> {
+        let path = format!("{}/masterleagues/by-queue/{}", ENDPOINT, queue);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "league-v4.getMasterLeague",
+            path,
+        ))
+    }
+
+    pub fn by_league_id(&self, league_id: &str) -> C::Output<LeagueList> {
+        let path = format!("{}/leagues/{}", ENDPOINT, league_id);
+        self.client
+            .execute(ApiRequest::new(self.route, "league-v4.getLeagueById", path))
+    }
+
+    /// One page of the players in a division, starting at page 1. An empty
+    /// page means there are no more entries.
+    pub fn entries(
+        &self,
+        queue: RankedQueue,
+        tier: Tier,
+        division: Division,
+        page: u32,
+    ) -> C::Output<Vec<LeagueEntry>> {
+        let path = format!(
+            "{}/entries/{}/{}/{}?page={}",
+            ENDPOINT, queue, tier, division, page
+        );
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "league-v4.getLeagueEntries",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder, PUUID, SUMMONER_ID};
+
+    #[test]
+    fn test_requests() {
+        let api = LeagueV4::with_client(Recorder, Platform::NA1);
+        testing::assert_requests(&[
+            (
+                api.entries_by_summoner(&SUMMONER_ID.parse().unwrap()),
+                testing::get(
+                    Platform::NA1,
+                    "league-v4.getLeagueEntriesForSummoner",
+                    format!("{}/entries/by-summoner/{}", ENDPOINT, SUMMONER_ID),
+                ),
+            ),
+            (
+                api.entries_by_puuid(&testing::puuid()),
+                testing::get(
+                    Platform::NA1,
+                    "league-v4.getLeagueEntriesByPUUID",
+                    format!("{}/entries/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.challenger(RankedQueue::Solo),
+                testing::get(
+                    Platform::NA1,
+                    "league-v4.getChallengerLeague",
+                    format!("{}/challengerleagues/by-queue/RANKED_SOLO_5x5", ENDPOINT),
+                ),
+            ),
+            (
+                api.grandmaster(RankedQueue::Flex),
+                testing::get(
+                    Platform::NA1,
+                    "league-v4.getGrandmasterLeague",
+                    format!("{}/grandmasterleagues/by-queue/RANKED_FLEX_SR", ENDPOINT),
+                ),
+            ),
+            (
+                api.master(RankedQueue::Other("RANKED_FLEX_TT".to_string())),
+                testing::get(
+                    Platform::NA1,
+                    "league-v4.getMasterLeague",
+                    format!("{}/masterleagues/by-queue/RANKED_FLEX_TT", ENDPOINT),
+                ),
+            ),
+            (
+                api.by_league_id("league-1"),
+                testing::get(
+                    Platform::NA1,
+                    "league-v4.getLeagueById",
+                    format!("{}/leagues/league-1", ENDPOINT),
+                ),
+            ),
+            (
+                api.entries(RankedQueue::Flex, Tier::Gold, Division::IV, 3),
+                testing::get(
+                    Platform::NA1,
+                    "league-v4.getLeagueEntries",
+                    format!("{}/entries/RANKED_FLEX_SR/GOLD/IV?page=3", ENDPOINT),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_decode_entries() {
+        let res: Vec<LeagueEntry> = testing::decode("league_v4/entries.json");
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].queue_type, RankedQueue::Solo);
+        assert_eq!((res[0].tier, res[0].rank), (Tier::Emerald, Division::II));
+        assert_eq!(res[0].league_points, 63);
+        assert_eq!(res[0].summoner_id.as_ref().unwrap(), SUMMONER_ID);
+        assert_eq!(res[0].mini_series, None);
+        assert_eq!(res[1].queue_type, RankedQueue::Flex);
+        assert_eq!(res[1].mini_series.as_ref().unwrap().progress, "WNN");
+    }
+
+    #[test]
+    fn test_decode_league() {
+        let res: LeagueList = testing::decode("league_v4/challenger.json");
+        assert_eq!(res.tier, Tier::Challenger);
+        assert_eq!(res.entries.len(), 2);
+        assert_eq!(res.entries[0].league_points, 1542);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Tier::Challenger > Tier::Grandmaster);
+        assert!(Tier::Emerald > Tier::Platinum);
+        assert!(Tier::Master.is_apex() && !Tier::Diamond.is_apex());
+        assert!(Division::I > Division::IV);
+    }
+
+    #[test]
+    fn test_unknown_queue() {
+        let queue: RankedQueue = serde_json::from_str(r#""RANKED_FLEX_TT""#).unwrap();
+        assert_eq!(queue, RankedQueue::Other("RANKED_FLEX_TT".to_string()));
+        assert_eq!(
+            serde_json::to_string(&queue).unwrap(),
+            r#""RANKED_FLEX_TT""#
+        );
+        let solo: RankedQueue = serde_json::from_str(r#""RANKED_SOLO_5x5""#).unwrap();
+        assert_eq!(solo, RankedQueue::Solo);
+    }
+}
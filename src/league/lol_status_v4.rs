@@ -0,0 +1,226 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    Route,
+};
+pub use crate::{error::Error, Platform};
+
+const ENDPOINT: &str = "/lol/status/v4";
+
+/// Locale used when a status has no text in the requested one.
+const FALLBACK_LOCALE: &str = "en_US";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MaintenanceStatus {
+    Scheduled,
+    InProgress,
+    Complete,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentSeverity {
+    Info,
+    Warning,
+    Critical,
+    #[serde(other)]
+    Other,
+}
+
+/// Where Riot shows a status update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PublishLocation {
+    RiotClient,
+    RiotStatus,
+    Game,
+    #[serde(other)]
+    Other,
+}
+
+/// The maintenances and incidents currently posted for a platform.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlatformData {
+    pub id: String,
+    pub name: String,
+    pub locales: Vec<String>,
+    pub maintenances: Vec<Status>,
+    pub incidents: Vec<Status>,
+}
+
+impl PlatformData {
+    /// Whether any posted incident has been published to the game client,
+    /// i.e. Riot considers it to affect players in game.
+    pub fn has_active_incident(&self) -> bool {
+        self.incidents.iter().any(Status::affects_game)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Status {
+    pub id: i64,
+    pub maintenance_status: Option<MaintenanceStatus>,
+    pub incident_severity: Option<IncidentSeverity>,
+    pub titles: Vec<Content>,
+    pub updates: Vec<Update>,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+    pub archive_at: Option<String>,
+    pub updated_at: Option<String>,
+    /// Client platforms affected, e.g. `windows` or `macos`.
+    pub platforms: Vec<String>,
+}
+
+impl Status {
+    /// The title in `locale`, falling back to `en_US`.
+    pub fn title(&self, locale: &str) -> Option<&str> {
+        localized(&self.titles, locale)
+    }
+
+    pub fn affects_game(&self) -> bool {
+        self.updates.iter().any(|update| {
+            update.publish && update.publish_locations.contains(&PublishLocation::Game)
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Update {
+    pub id: i64,
+    pub author: String,
+    pub publish: bool,
+    pub publish_locations: Vec<PublishLocation>,
+    pub translations: Vec<Content>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+impl Update {
+    /// The update's text in `locale`, falling back to `en_US`.
+    pub fn content(&self, locale: &str) -> Option<&str> {
+        localized(&self.translations, locale)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Content {
+    pub locale: String,
+    pub content: String,
+}
+
+fn localized<'a>(contents: &'a [Content], locale: &str) -> Option<&'a str> {
+    let find = |locale: &str| contents.iter().find(|c| c.locale == locale);
+    find(locale)
+        .or_else(|| find(FALLBACK_LOCALE))
+        .map(|c| c.content.as_str())
+}
+
+#[derive(Debug, Clone)]
+pub struct LolStatusV4<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl LolStatusV4<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).lol_status_v4(platform)
+    }
+}
+
+impl<C: Executor> LolStatusV4<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn platform_data(&self) -> C::Output<PlatformData> {
+        let path = format!("{}/platform-data", ENDPOINT);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "lol-status-v4.getPlatformData",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder};
+
+    #[test]
+    fn test_requests() {
+        let api = LolStatusV4::with_client(Recorder, Platform::EUW1);
+        testing::assert_requests(&[(
+            api.platform_data(),
+            testing::get(
+                Platform::EUW1,
+                "lol-status-v4.getPlatformData",
+                format!("{}/platform-data", ENDPOINT),
+            ),
+        )]);
+    }
+
+    #[test]
+    fn test_decode() {
+        let res: PlatformData = testing::decode("lol_status_v4/platform_data.json");
+        assert_eq!(res.id, "EUW1");
+
+        let maintenance = &res.maintenances[0];
+        assert_eq!(
+            maintenance.maintenance_status,
+            Some(MaintenanceStatus::Scheduled)
+        );
+        assert_eq!(maintenance.title("de_DE"), Some("Geplante Wartung"));
+        assert!(!maintenance.affects_game());
+
+        let incident = &res.incidents[0];
+        assert_eq!(incident.incident_severity, Some(IncidentSeverity::Warning));
+        assert_eq!(incident.title("fr_FR"), Some("Problèmes de connexion"));
+        assert_eq!(incident.archive_at, None);
+        assert!(incident.updates[0]
+            .content("en_GB")
+            .unwrap()
+            .contains("log in"));
+        assert!(res.has_active_incident());
+    }
+
+    #[test]
+    fn test_localized_fallback() {
+        let titles = vec![
+            Content {
+                locale: "en_US".into(),
+                content: "Login Issues".into(),
+            },
+            Content {
+                locale: "ko_KR".into(),
+                content: "로그인 문제".into(),
+            },
+        ];
+        assert_eq!(localized(&titles, "ko_KR"), Some("로그인 문제"));
+        assert_eq!(localized(&titles, "pl_PL"), Some("Login Issues"));
+        assert_eq!(localized(&titles[1..], "pl_PL"), None);
+    }
+
+    #[test]
+    fn test_no_incidents() {
+        let mut data: PlatformData = testing::decode("lol_status_v4/platform_data.json");
+        data.incidents[0].updates[0].publish = false;
+        assert!(!data.has_active_incident());
+        data.incidents.clear();
+        assert!(!data.has_active_incident());
+    }
+}
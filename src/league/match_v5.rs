@@ -1,26 +1,30 @@
-use reqwest::{
-    blocking::Client,
-    header::{HeaderMap, HeaderValue},
-    StatusCode,
-};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{query_string, ApiRequest, Executor},
+    ids::{MatchId, MatchIds, Puuid, RiotId, SummonerId},
+    Route,
+};
 pub use crate::{error::Error, Region};
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+const ENDPOINT: &str = "/lol/match/v5/matches";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchInfo {
     pub metadata: Metadata,
     pub info: Info,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Metadata {
     pub data_version: String,
-    pub match_id: String,
-    pub participants: Vec<String>,
+    pub match_id: MatchId,
+    pub participants: Vec<Puuid>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -43,7 +47,7 @@ pub struct Info {
     pub tournament_code: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Participant {
     pub assists: i64,
@@ -106,7 +110,7 @@ pub struct Participant {
     pub physical_damage_dealt_to_champions: i64,
     pub physical_damage_taken: i64,
     pub profile_icon: i64,
-    pub puuid: String,
+    pub puuid: Puuid,
     pub quadra_kills: i64,
     pub riot_id_name: String,
     pub riot_id_tagline: String,
@@ -128,7 +132,7 @@ pub struct Participant {
     pub summoner2casts: i64,
     #[serde(rename = "summoner2Id")]
     pub summoner2id: i64,
-    pub summoner_id: String,
+    pub summoner_id: SummonerId,
     pub summoner_level: i64,
     pub summoner_name: String,
     pub team_early_surrendered: bool,
@@ -163,6 +167,16 @@ pub struct Participant {
     pub win: bool,
 }
 
+impl Participant {
+    /// The participant's Riot ID, when the match data carries one.
+    pub fn riot_id(&self) -> Option<RiotId> {
+        if self.riot_id_name.is_empty() || self.riot_id_tagline.is_empty() {
+            return None;
+        }
+        Some(RiotId::new(&self.riot_id_name, &self.riot_id_tagline))
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Perks {
@@ -257,7 +271,7 @@ pub struct Tower {
     pub kills: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchTimeline {
     pub metadata: Metadata,
@@ -445,14 +459,14 @@ pub struct DamageStats {
     pub true_damage_taken: i64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TimelineParticipant {
     pub participant_id: i64,
-    pub puuid: String,
+    pub puuid: Puuid,
 }
 
-#[derive(Debug)]
+#[derive(Default, Debug)]
 pub struct ByPUUIDOptions {
     pub start_time: Option<u64>,
     pub end_time: Option<u64>,
@@ -462,186 +476,174 @@ pub struct ByPUUIDOptions {
     pub count: Option<u8>,
 }
 
-#[derive(Debug)]
-pub struct MatchV5 {
-    client: Client,
-    endpoint: String,
+#[derive(Debug, Clone)]
+pub struct MatchV5<C> {
+    client: C,
+    route: Route,
 }
 
-impl MatchV5 {
-    pub fn new(key: &str, region: Region) -> Self {
-        let endpoint = format!(
-            "https://{region}.api.riotgames.com/lol/match/v5/matches",
-            region = region.to_string().to_ascii_lowercase()
-        );
-
-        let mut headers = HeaderMap::new();
-        headers.insert("X-Riot-Token", HeaderValue::from_str(key).unwrap());
+#[cfg(feature = "blocking")]
+impl MatchV5<RiotClient> {
+    pub fn new(key: &str, region: impl Into<Region>) -> Self {
+        RiotClient::new(key).match_v5(region)
+    }
+}
 
-        let client = Client::builder().default_headers(headers).build().unwrap();
+impl<C: Executor> MatchV5<C> {
+    pub(crate) fn with_client(client: C, region: impl Into<Region>) -> Self {
+        Self {
+            client,
+            route: Route::Region(region.into()),
+        }
+    }
 
-        Self { client, endpoint }
+    pub fn client(&self) -> &C {
+        &self.client
     }
 
     fn stringify_options(options: ByPUUIDOptions) -> String {
-        let mut s = String::new();
-        if let Some(val) = options.start {
-            let opt = format!("start={}", val);
-            s.push_str(&opt);
-        }
-        if let Some(val) = options.count {
-            if !s.ends_with('&') {
-                s.push('&');
-            }
-            let opt = format!("count={}", val);
-            s.push_str(&opt);
-        }
-        if let Some(val) = options.queue {
-            if !s.ends_with('&') {
-                s.push('&');
-            }
-            let opt = format!("queue={}", val);
-            s.push_str(&opt);
-        }
-        if let Some(val) = options.match_type {
-            if !s.ends_with('&') {
-                s.push('&');
-            }
-            let opt = format!("type={}", val);
-            s.push_str(&opt);
-        }
-        if let Some(val) = options.start_time {
-            if !s.ends_with('&') {
-                s.push('&');
-            }
-            let opt = format!("startTime={}", val);
-            s.push_str(&opt);
-        }
-        if let Some(val) = options.end_time {
-            if !s.ends_with('&') {
-                s.push('&');
-            }
-            let opt = format!("endTime={}", val);
-            s.push_str(&opt);
-        }
-        if !s.is_empty() {
-            s.insert(0, '?');
-        }
-        s
+        query_string(&[
+            ("start", options.start.map(|v| v.to_string())),
+            ("count", options.count.map(|v| v.to_string())),
+            ("queue", options.queue.map(|v| v.to_string())),
+            ("type", options.match_type),
+            ("startTime", options.start_time.map(|v| v.to_string())),
+            ("endTime", options.end_time.map(|v| v.to_string())),
+        ])
     }
 
-    pub fn by_puuid(&self, puuid: &str, options: ByPUUIDOptions) -> Result<Vec<String>, Error> {
-        let url = format!(
+    pub fn by_puuid(&self, puuid: &Puuid, options: ByPUUIDOptions) -> C::Output<MatchIds> {
+        let path = format!(
             "{}/by-puuid/{}/ids{}",
-            self.endpoint,
+            ENDPOINT,
             puuid,
             Self::stringify_options(options)
         );
-        let request = self.client.get(&url);
-        let response = request.send();
-        if let Err(error) = response {
-            return Err(Error::new_message(&format!("Failed:\n{}", error)));
-        }
-
-        let response = response.unwrap();
-
-        if response.status() != StatusCode::OK {
-            return Err(Error::StatusCode(response.status()));
-        }
-
-        match response.json::<Vec<String>>() {
-            Ok(val) => Ok(val),
-            Err(err) => Err(Error::Message(err.to_string())),
-        }
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "match-v5.getMatchIdsByPUUID",
+            path,
+        ))
     }
 
-    pub fn match_info(&self, match_id: &str) -> Result<MatchInfo, Error> {
-        let url = format!("{}/{}", self.endpoint, match_id);
-        let request = self.client.get(&url);
-        let response = request.send();
-        if let Err(error) = response {
-            return Err(Error::new_message(&format!("Failed:\n{}", error)));
-        }
-
-        let response = response.unwrap();
-
-        if response.status() != StatusCode::OK {
-            return Err(Error::StatusCode(response.status()));
-        }
-
-        match response.json::<MatchInfo>() {
-            Ok(val) => Ok(val),
-            Err(err) => Err(Error::Message(err.to_string())),
-        }
+    /// Routed by [`MatchId::region`].
+    pub fn match_info(&self, match_id: &MatchId) -> C::Output<MatchInfo> {
+        let path = format!("{}/{}", ENDPOINT, match_id);
+        let route = match_id.region().into();
+        self.client
+            .execute(ApiRequest::new(route, "match-v5.getMatch", path))
     }
 
-    pub fn match_timeline(&self, match_id: &str) -> Result<MatchTimeline, Error> {
-        let url = format!("{}/{}/timeline", self.endpoint, match_id);
-        let request = self.client.get(&url);
-        let response = request.send();
-        if let Err(error) = response {
-            return Err(Error::new_message(&format!("Failed:\n{}", error)));
-        }
-
-        let response = response.unwrap();
-
-        if response.status() != StatusCode::OK {
-            return Err(Error::StatusCode(response.status()));
-        }
-
-        match response.json::<MatchTimeline>() {
-            Ok(val) => Ok(val),
-            Err(err) => Err(Error::Message(err.to_string())),
-        }
+    pub fn match_timeline(&self, match_id: &MatchId) -> C::Output<MatchTimeline> {
+        let path = format!("{}/{}/timeline", ENDPOINT, match_id);
+        let route = match_id.region().into();
+        self.client
+            .execute(ApiRequest::new(route, "match-v5.getTimeline", path))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::env::var;
-
-    use dotenv::dotenv;
-
     use super::*;
+    use crate::{
+        testing::{self, Recorder, MATCH_ID, PUUID},
+        Platform,
+    };
 
     #[test]
-    fn test_by_puuid() {
-        dotenv().ok();
-        let key = var("API_KEY").unwrap();
-        let puuid = var("PUUID").unwrap();
-        let region = Region::AMERICAS;
-        let api = MatchV5::new(&key, region);
+    fn test_new_from_platform() {
+        let api = MatchV5::with_client(Recorder, Platform::KR);
+        assert_eq!(api.route, Route::Region(Region::ASIA));
+        let api = MatchV5::with_client(Recorder, Platform::EUW1);
+        assert_eq!(api.route, Route::Region(Region::EUROPE));
+    }
+
+    #[test]
+    fn test_requests() {
+        let api = MatchV5::with_client(Recorder, Region::AMERICAS);
+        let match_id: MatchId = MATCH_ID.parse().unwrap();
         let options = ByPUUIDOptions {
-            start_time: None,
-            end_time: None,
-            queue: None,
-            match_type: None,
             start: Some(1),
             count: Some(100),
+            ..Default::default()
         };
-        let res = api.by_puuid(&puuid, options).unwrap();
-        println!("{:?}", res);
+        testing::assert_requests(&[
+            (
+                api.by_puuid(&testing::puuid(), options),
+                testing::get(
+                    Region::AMERICAS,
+                    "match-v5.getMatchIdsByPUUID",
+                    format!("{}/by-puuid/{}/ids?start=1&count=100", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.match_info(&match_id),
+                testing::get(
+                    Region::AMERICAS,
+                    "match-v5.getMatch",
+                    format!("{}/{}", ENDPOINT, MATCH_ID),
+                ),
+            ),
+            (
+                api.match_timeline(&match_id),
+                testing::get(
+                    Region::AMERICAS,
+                    "match-v5.getTimeline",
+                    format!("{}/{}/timeline", ENDPOINT, MATCH_ID),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_match_routed_by_id() {
+        let api = MatchV5::with_client(Recorder, Region::AMERICAS);
+        let id = MatchId::new(Platform::VN2, 712345678);
+        assert_eq!(api.match_info(&id).route, Route::Region(Region::SEA));
+        assert_eq!(api.match_timeline(&id).route, Route::Region(Region::SEA));
     }
 
     #[test]
-    fn test_get_match_info() {
-        dotenv().ok();
-        let key = var("API_KEY").unwrap();
-        let match_id = var("MATCH_ID").unwrap();
-        let region = Region::AMERICAS;
-        let api = MatchV5::new(&key, region);
-        let res = api.match_info(&match_id).unwrap();
-        println!("{:#?}", res);
+    fn test_stringify_options() {
+        let query = MatchV5::<Recorder>::stringify_options;
+        assert_eq!(query(ByPUUIDOptions::default()), "");
+        let options = ByPUUIDOptions {
+            count: Some(5),
+            match_type: Some("ranked".to_string()),
+            end_time: Some(1698192000),
+            ..Default::default()
+        };
+        assert_eq!(query(options), "?count=5&type=ranked&endTime=1698192000");
+    }
+
+    #[test]
+    fn test_decode_match_ids() {
+        let res: MatchIds = testing::decode("match_v5/match_ids.json");
+        assert_eq!(res.len(), 5);
+        assert_eq!(res.ids[0].to_string(), MATCH_ID);
+    }
+
+    #[test]
+    fn test_decode_match() {
+        let res: MatchInfo = testing::decode("match_v5/match.json");
+        assert_eq!(res.metadata.match_id.to_string(), MATCH_ID);
+        assert_eq!(res.info.participants.len(), 10);
+        assert_eq!(res.info.participants[0].puuid, PUUID);
+        assert_eq!(
+            res.info.participants[0].riot_id().unwrap().to_string(),
+            "Fixture Summoner#NA1"
+        );
+        assert_eq!(res.info.participants[0].perks.styles.len(), 2);
+        assert_eq!(res.info.teams.len(), 2);
     }
 
     #[test]
-    fn test_get_match_timeline() {
-        dotenv().ok();
-        let key = var("API_KEY").unwrap();
-        let match_id = var("MATCH_ID").unwrap();
-        let region = Region::AMERICAS;
-        let api = MatchV5::new(&key, region);
-        let res = api.match_timeline(&match_id).unwrap();
-        println!("{:#?}", res);
+    fn test_decode_timeline() {
+        let res: MatchTimeline = testing::decode("match_v5/timeline.json");
+        assert_eq!(res.metadata.match_id.to_string(), MATCH_ID);
+        assert_eq!(res.info.participants[0].puuid, PUUID);
+        let last = res.info.frames.last().unwrap().events.last().unwrap();
+        assert_eq!(last.type_field, "GAME_END");
+        assert_eq!(last.winning_team, Some(100));
     }
 }
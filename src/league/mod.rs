@@ -1,2 +1,13 @@
+pub mod challenges_v1;
+pub mod champion_mastery_v4;
+pub mod champion_v3;
+pub mod clash_v1;
+pub mod league_exp_v4;
+pub mod league_v4;
+pub mod lol_status_v4;
 pub mod match_v5;
+pub mod spectator_v5;
 pub mod summoner_v4;
+pub mod tournament_callback;
+pub mod tournament_stub_v5;
+pub mod tournament_v5;
@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    ids::Puuid,
+    Route,
+};
+pub use crate::{error::Error, Platform};
+
+const ENDPOINT: &str = "/lol/spectator/v5";
+
+/// A game in progress, as seen by the spectator client.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameInfo {
+    pub game_id: i64,
+    pub map_id: i64,
+    pub game_mode: String,
+    pub game_type: String,
+    pub game_queue_config_id: Option<i64>,
+    pub participants: Vec<GameParticipant>,
+    pub observers: Observer,
+    pub platform_id: String,
+    pub banned_champions: Vec<BannedChampion>,
+    /// Milliseconds since the Unix epoch.
+    pub game_start_time: i64,
+    /// Seconds since the game started.
+    pub game_length: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameParticipant {
+    pub puuid: Option<Puuid>,
+    pub team_id: i64,
+    pub spell1_id: i64,
+    pub spell2_id: i64,
+    pub champion_id: i64,
+    pub profile_icon_id: i64,
+    /// Formatted `gameName#tagLine`.
+    pub riot_id: Option<String>,
+    pub bot: bool,
+    /// Only sent for active games, not featured ones.
+    pub perks: Option<Perks>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Perks {
+    pub perk_ids: Vec<i64>,
+    pub perk_style: i64,
+    pub perk_sub_style: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Observer {
+    /// Key the spectator client needs to watch the game.
+    pub encryption_key: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BannedChampion {
+    pub champion_id: i64,
+    pub team_id: i64,
+    pub pick_turn: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeaturedGames {
+    pub game_list: Vec<GameInfo>,
+    /// Seconds until the list should be fetched again.
+    pub client_refresh_interval: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpectatorV5<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl SpectatorV5<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).spectator_v5(platform)
+    }
+}
+
+impl<C: Executor> SpectatorV5<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// The game the player is in right now, or `None` when they aren't in one.
+    pub fn active_game(&self, puuid: &Puuid) -> C::Output<Option<GameInfo>> {
+        let path = format!("{}/active-games/by-summoner/{}", ENDPOINT, puuid);
+        self.client.execute_optional(ApiRequest::new(
+            self.route,
+            "spectator-v5.getCurrentGameInfoByPuuid",
+            path,
+        ))
+    }
+
+    pub fn featured_games(&self) -> C::Output<FeaturedGames> {
+        let path = format!("{}/featured-games", ENDPOINT);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "spectator-v5.getFeaturedGames",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder, PUUID};
+
+    #[test]
+    fn test_requests() {
+        let api = SpectatorV5::with_client(Recorder, Platform::NA1);
+        testing::assert_requests(&[
+            (
+                api.active_game(&testing::puuid()),
+                testing::get(
+                    Platform::NA1,
+                    "spectator-v5.getCurrentGameInfoByPuuid",
+                    format!("{}/active-games/by-summoner/{}", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.featured_games(),
+                testing::get(
+                    Platform::NA1,
+                    "spectator-v5.getFeaturedGames",
+                    format!("{}/featured-games", ENDPOINT),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_decode_active_game() {
+        let game: GameInfo = testing::decode("spectator_v5/active_game.json");
+        assert_eq!(game.game_queue_config_id, Some(420));
+        assert_eq!(game.game_length, 754);
+        assert_eq!(game.participants.len(), 2);
+        assert_eq!(game.participants[0].puuid.as_ref().unwrap(), PUUID);
+        assert_eq!(
+            game.participants[0].perks.as_ref().unwrap().perk_style,
+            8100
+        );
+        assert_eq!(game.banned_champions[1].champion_id, 777);
+        assert!(!game.observers.encryption_key.is_empty());
+    }
+
+    #[test]
+    fn test_decode_featured_games() {
+        let res: FeaturedGames = testing::decode("spectator_v5/featured_games.json");
+        assert_eq!(res.client_refresh_interval, Some(300));
+        assert_eq!(res.game_list[0].game_mode, "ARAM");
+        assert_eq!(res.game_list[0].participants[0].perks, None);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_not_in_game() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock(
+                "GET",
+                format!("{}/active-games/by-summoner/{}", ENDPOINT, PUUID).as_str(),
+            )
+            .with_status(404)
+            .with_body(r#"{"status":{"message":"Data not found","status_code":404}}"#)
+            .create();
+        let api = testing::client(&server).spectator_v5(Platform::NA1);
+        assert_eq!(api.active_game(&testing::puuid()).unwrap(), None);
+        mock.assert();
+    }
+}
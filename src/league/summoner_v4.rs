@@ -1,136 +1,154 @@
-use reqwest::{
-    blocking::Client,
-    header::{HeaderMap, HeaderValue},
-    StatusCode,
-};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    ids::{AccountId, Puuid, SummonerId},
+    Route,
+};
 pub use crate::{error::Error, Platform};
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+const ENDPOINT: &str = "/lol/summoner/v4/summoners";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Response {
-    pub id: String,
-    pub account_id: String,
-    pub puuid: String,
+    pub id: SummonerId,
+    pub account_id: AccountId,
+    pub puuid: Puuid,
     pub name: String,
     pub profile_icon_id: u16,
     pub revision_date: u64,
     pub summoner_level: u16,
 }
 
-#[derive(Debug)]
-pub struct SummonerV4 {
-    client: Client,
-    endpoint: String,
+#[derive(Debug, Clone)]
+pub struct SummonerV4<C> {
+    client: C,
+    route: Route,
 }
 
-impl SummonerV4 {
+#[cfg(feature = "blocking")]
+impl SummonerV4<RiotClient> {
     pub fn new(key: &str, platform: Platform) -> Self {
-        let endpoint = format!(
-            "https://{platform}.api.riotgames.com/lol/summoner/v4/summoners",
-            platform = platform.to_string().to_ascii_lowercase()
-        );
-
-        let mut headers = HeaderMap::new();
-        headers.insert("X-Riot-Token", HeaderValue::from_str(key).unwrap());
-
-        let client = Client::builder().default_headers(headers).build().unwrap();
-
-        Self { client, endpoint }
+        RiotClient::new(key).summoner_v4(platform)
     }
+}
 
-    fn invoke(&self, url: &str) -> Result<Response, Error> {
-        let request = self.client.get(url);
-        let response = request.send();
-        if let Err(error) = response {
-            return Err(Error::new_message(&format!("Failed:\n{}", error)));
-        }
-
-        let response = response.unwrap();
-
-        if response.status() != StatusCode::OK {
-            return Err(Error::StatusCode(response.status()));
+impl<C: Executor> SummonerV4<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
         }
+    }
 
-        match response.json::<Response>() {
-            Ok(val) => Ok(val),
-            Err(err) => Err(Error::new_message(&format!(
-                "Couldn't Deserialize:\n{}",
-                err
-            ))),
-        }
+    pub fn client(&self) -> &C {
+        &self.client
     }
 
-    pub fn by_account(&self, encrypted_account_id: &str) -> Result<Response, Error> {
-        let url = format!("{}/by-account/{}", self.endpoint, encrypted_account_id);
-        self.invoke(&url)
+    pub fn by_account(&self, encrypted_account_id: &AccountId) -> C::Output<Response> {
+        let path = format!("{}/by-account/{}", ENDPOINT, encrypted_account_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "summoner-v4.getByAccountId",
+            path,
+        ))
     }
 
-    pub fn by_name(&self, summoner_name: &str) -> Result<Response, Error> {
-        let url = format!("{}/by-name/{}", self.endpoint, summoner_name);
-        self.invoke(&url)
+    pub fn by_name(&self, summoner_name: &str) -> C::Output<Response> {
+        let path = format!("{}/by-name/{}", ENDPOINT, summoner_name);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "summoner-v4.getBySummonerName",
+            path,
+        ))
     }
 
-    pub fn by_puuid(&self, encrypted_puuid: &str) -> Result<Response, Error> {
-        let url = format!("{}/by-puuid/{}", self.endpoint, encrypted_puuid);
-        self.invoke(&url)
+    pub fn by_puuid(&self, encrypted_puuid: &Puuid) -> C::Output<Response> {
+        let path = format!("{}/by-puuid/{}", ENDPOINT, encrypted_puuid);
+        self.client
+            .execute(ApiRequest::new(self.route, "summoner-v4.getByPUUID", path))
     }
 
-    pub fn by_summoner_id(&self, summoner_name: &str) -> Result<Response, Error> {
-        let url = format!("{}/{}", self.endpoint, summoner_name);
-        self.invoke(&url)
+    pub fn by_summoner_id(&self, encrypted_summoner_id: &SummonerId) -> C::Output<Response> {
+        let path = format!("{}/{}", ENDPOINT, encrypted_summoner_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "summoner-v4.getBySummonerId",
+            path,
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::env::var;
-
-    use dotenv::dotenv;
-
     use super::*;
+    use crate::testing::{self, Recorder, ACCOUNT_ID, PUUID, SUMMONER_ID, SUMMONER_NAME};
 
     #[test]
-    fn test_by_account() {
-        dotenv().ok();
-        let key = var("API_KEY").unwrap();
-        let id = var("ACCOUNT_ID").unwrap();
-        let platform = Platform::NA1;
-        let api = SummonerV4::new(&key, platform);
-        let res = api.by_account(&id).unwrap();
-        assert_eq!(res.account_id, id);
+    fn test_requests() {
+        let api = SummonerV4::with_client(Recorder, Platform::NA1);
+        testing::assert_requests(&[
+            (
+                api.by_account(&ACCOUNT_ID.parse().unwrap()),
+                testing::get(
+                    Platform::NA1,
+                    "summoner-v4.getByAccountId",
+                    format!("{}/by-account/{}", ENDPOINT, ACCOUNT_ID),
+                ),
+            ),
+            (
+                api.by_name(SUMMONER_NAME),
+                testing::get(
+                    Platform::NA1,
+                    "summoner-v4.getBySummonerName",
+                    format!("{}/by-name/{}", ENDPOINT, SUMMONER_NAME),
+                ),
+            ),
+            (
+                api.by_puuid(&testing::puuid()),
+                testing::get(
+                    Platform::NA1,
+                    "summoner-v4.getByPUUID",
+                    format!("{}/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.by_summoner_id(&SUMMONER_ID.parse().unwrap()),
+                testing::get(
+                    Platform::NA1,
+                    "summoner-v4.getBySummonerId",
+                    format!("{}/{}", ENDPOINT, SUMMONER_ID),
+                ),
+            ),
+        ]);
     }
 
     #[test]
-    fn test_by_name() {
-        dotenv().ok();
-        let key = var("API_KEY").unwrap();
-        let name = var("NAME").unwrap();
-        let platform = Platform::NA1;
-        let api = SummonerV4::new(&key, platform);
-        let res = api.by_name(&name).unwrap();
-        assert_eq!(res.name, name);
+    fn test_decode() {
+        let res: Response = testing::decode("summoner_v4/summoner.json");
+        assert_eq!(res.id, SUMMONER_ID);
+        assert_eq!(res.account_id, ACCOUNT_ID);
+        assert_eq!(res.puuid, PUUID);
+        assert_eq!(res.name, SUMMONER_NAME);
     }
 
+    #[cfg(feature = "blocking")]
     #[test]
-    fn test_by_puuid() {
-        dotenv().ok();
-        let key = var("API_KEY").unwrap();
-        let puuid = var("PUUID").unwrap();
-        let platform = Platform::NA1;
-        let api = SummonerV4::new(&key, platform);
-        let res = api.by_puuid(&puuid).unwrap();
-        assert_eq!(res.puuid, puuid);
-    }
-    #[test]
-    fn test_by_summoner_id() {
-        dotenv().ok();
-        let key = var("API_KEY").unwrap();
-        let id = var("SUMMONER_ID").unwrap();
-        let platform = Platform::NA1;
-        let api = SummonerV4::new(&key, platform);
-        let res = api.by_summoner_id(&id).unwrap();
-        assert_eq!(res.id, id);
+    fn test_by_name_sent_encoded() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock(
+                "GET",
+                format!("{}/by-name/Fixture%20Summoner", ENDPOINT).as_str(),
+            )
+            .match_header("X-Riot-Token", "key")
+            .with_body(testing::fixture("summoner_v4/summoner.json"))
+            .create();
+        let api = testing::client(&server).summoner_v4(Platform::NA1);
+        assert_eq!(api.by_name(SUMMONER_NAME).unwrap().name, SUMMONER_NAME);
+        mock.assert();
     }
 }
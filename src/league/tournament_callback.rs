@@ -0,0 +1,195 @@
+use std::{error::Error as StdError, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::Executor,
+    error::DecodeError,
+    ids::MatchId,
+    league::{
+        match_v5::{MatchInfo, MatchV5},
+        tournament_v5::TournamentPlayer,
+    },
+    Platform,
+};
+
+/// The game result Riot posts to a tournament provider's callback URL once a
+/// game played with one of its codes ends.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GameCallback {
+    /// Milliseconds since the Unix epoch.
+    pub start_time: Option<i64>,
+    /// The tournament code the game was played with.
+    pub short_code: String,
+    /// The metadata the code was created with.
+    pub meta_data: Option<String>,
+    pub game_id: u64,
+    pub game_name: String,
+    pub game_type: String,
+    pub game_map: i64,
+    pub game_mode: String,
+    pub region: Platform,
+    pub winning_team: Vec<TournamentPlayer>,
+    pub losing_team: Vec<TournamentPlayer>,
+}
+
+/// A callback as posted, with the fields `parse` requires left optional so a
+/// missing one is reported as [`CallbackError::Invalid`] rather than a decode
+/// error.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Payload {
+    start_time: Option<i64>,
+    short_code: Option<String>,
+    meta_data: Option<String>,
+    game_id: Option<u64>,
+    game_name: String,
+    game_type: String,
+    game_map: i64,
+    game_mode: String,
+    region: Platform,
+    #[serde(default)]
+    winning_team: Vec<TournamentPlayer>,
+    #[serde(default)]
+    losing_team: Vec<TournamentPlayer>,
+}
+
+impl GameCallback {
+    /// Parses a callback request body, rejecting payloads that are missing the
+    /// code, the game id or the winners.
+    pub fn parse(body: &[u8]) -> Result<Self, CallbackError> {
+        let payload: Payload =
+            DecodeError::from_slice(body, false).map_err(CallbackError::Decode)?;
+        let short_code = payload
+            .short_code
+            .filter(|code| !code.is_empty())
+            .ok_or(CallbackError::Invalid("missing shortCode"))?;
+        let game_id = payload
+            .game_id
+            .filter(|&id| id != 0)
+            .ok_or(CallbackError::Invalid("missing gameId"))?;
+        if payload.winning_team.is_empty() {
+            return Err(CallbackError::Invalid("missing winningTeam"));
+        }
+        Ok(Self {
+            start_time: payload.start_time,
+            short_code,
+            meta_data: payload.meta_data,
+            game_id,
+            game_name: payload.game_name,
+            game_type: payload.game_type,
+            game_map: payload.game_map,
+            game_mode: payload.game_mode,
+            region: payload.region,
+            winning_team: payload.winning_team,
+            losing_team: payload.losing_team,
+        })
+    }
+
+    pub fn match_id(&self) -> MatchId {
+        MatchId::new(self.region, self.game_id)
+    }
+
+    /// Fetches the full match, routed by the callback's platform whatever
+    /// region `api` was created for.
+    pub fn match_info<C: Executor>(&self, api: &MatchV5<C>) -> C::Output<MatchInfo> {
+        api.match_info(&self.match_id())
+    }
+}
+
+#[derive(Debug)]
+pub enum CallbackError {
+    /// The body isn't a callback payload.
+    Decode(DecodeError),
+    /// The payload decoded but is missing a field needed to ingest the game.
+    Invalid(&'static str),
+}
+
+impl fmt::Display for CallbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(source) => write!(f, "invalid tournament callback: {}", source),
+            Self::Invalid(reason) => write!(f, "invalid tournament callback: {}", reason),
+        }
+    }
+}
+
+impl StdError for CallbackError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Decode(source) => Some(source),
+            Self::Invalid(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{self, Recorder, MATCH_ID, PUUID},
+        Region, Route,
+    };
+
+    #[test]
+    fn test_parse() {
+        let body = testing::fixture("tournament_v5/callback.json");
+        let callback = GameCallback::parse(body.as_bytes()).unwrap();
+        assert_eq!(callback.start_time, Some(1698179512000));
+        assert_eq!(callback.region, Platform::NA1);
+        assert_eq!(callback.winning_team[0].puuid, PUUID);
+        assert_eq!(
+            callback.meta_data.as_deref(),
+            Some(r#"{"round":2,"match":7}"#)
+        );
+        assert_eq!(callback.match_id().to_string(), MATCH_ID);
+
+        let json = serde_json::to_value(&callback).unwrap();
+        assert_eq!(json["region"], "NA1");
+        assert_eq!(json["startTime"], 1698179512000_i64);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let mut body: serde_json::Value =
+            serde_json::from_str(&testing::fixture("tournament_v5/callback.json")).unwrap();
+        body["region"] = "XX9".into();
+        let err = GameCallback::parse(body.to_string().as_bytes()).unwrap_err();
+        assert!(matches!(&err, CallbackError::Decode(source) if source.path == "region"));
+
+        body["region"] = "NA1".into();
+        for (field, reason) in [
+            ("shortCode", "missing shortCode"),
+            ("gameId", "missing gameId"),
+            ("winningTeam", "missing winningTeam"),
+        ] {
+            let mut body = body.clone();
+            body.as_object_mut().unwrap().remove(field);
+            let err = GameCallback::parse(body.to_string().as_bytes()).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("invalid tournament callback: {}", reason)
+            );
+        }
+
+        body["winningTeam"] = serde_json::json!([]);
+        let err = GameCallback::parse(body.to_string().as_bytes()).unwrap_err();
+        assert!(matches!(err, CallbackError::Invalid("missing winningTeam")));
+
+        assert!(matches!(
+            GameCallback::parse(b"shortCode=NA04"),
+            Err(CallbackError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_match_info() {
+        let body = testing::fixture("tournament_v5/callback.json");
+        let callback = GameCallback::parse(body.as_bytes()).unwrap();
+        let api = MatchV5::with_client(Recorder, Region::EUROPE);
+        let request = callback.match_info(&api);
+        assert_eq!(request.route, Route::Region(Region::AMERICAS));
+        assert_eq!(request.path, format!("/lol/match/v5/matches/{}", MATCH_ID));
+    }
+}
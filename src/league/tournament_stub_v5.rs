@@ -0,0 +1,164 @@
+use reqwest::Method;
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    Region, Route,
+};
+pub use crate::{
+    error::Error,
+    league::tournament_v5::{
+        LobbyEvents, MapType, PickType, ProviderRegistration, SpectatorType, TournamentCode,
+        TournamentCodeParameters, TournamentRegistration,
+    },
+};
+
+const ENDPOINT: &str = "/lol/tournament-stub/v5";
+
+/// The stub of [`TournamentV5`](crate::league::tournament_v5::TournamentV5),
+/// which takes the same requests without creating real lobbies. It has no
+/// code updates or game results.
+#[derive(Debug, Clone)]
+pub struct TournamentStubV5<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl TournamentStubV5<RiotClient> {
+    pub fn new(key: &str) -> Self {
+        RiotClient::new(key).tournament_stub_v5()
+    }
+}
+
+impl<C: Executor> TournamentStubV5<C> {
+    pub(crate) fn with_client(client: C) -> Self {
+        Self {
+            client,
+            route: Route::Region(Region::AMERICAS),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn register_provider(&self, registration: &ProviderRegistration) -> C::Output<i64> {
+        let path = format!("{}/providers", ENDPOINT);
+        self.client.execute(
+            ApiRequest::new(self.route, "tournament-stub-v5.registerProviderData", path)
+                .with_json(Method::POST, registration),
+        )
+    }
+
+    pub fn register_tournament(&self, registration: &TournamentRegistration) -> C::Output<i64> {
+        let path = format!("{}/tournaments", ENDPOINT);
+        self.client.execute(
+            ApiRequest::new(self.route, "tournament-stub-v5.registerTournament", path)
+                .with_json(Method::POST, registration),
+        )
+    }
+
+    pub fn create_codes(
+        &self,
+        tournament_id: i64,
+        count: u32,
+        parameters: &TournamentCodeParameters,
+    ) -> C::Output<Vec<String>> {
+        let path = format!(
+            "{}/codes?tournamentId={}&count={}",
+            ENDPOINT, tournament_id, count
+        );
+        self.client.execute(
+            ApiRequest::new(self.route, "tournament-stub-v5.createTournamentCode", path)
+                .with_json(Method::POST, parameters),
+        )
+    }
+
+    pub fn code(&self, code: &str) -> C::Output<TournamentCode> {
+        let path = format!("{}/codes/{}", ENDPOINT, code);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tournament-stub-v5.getTournamentCode",
+            path,
+        ))
+    }
+
+    pub fn lobby_events(&self, code: &str) -> C::Output<LobbyEvents> {
+        let path = format!("{}/lobby-events/by-code/{}", ENDPOINT, code);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tournament-stub-v5.getLobbyEventsByCode",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::testing::{self, Recorder};
+
+    const CODE: &str = "NA04a8e-2f9c61d7-5b3e-4a10-8d2c-7e41b9f05a63";
+
+    #[test]
+    fn test_requests() {
+        let api = TournamentStubV5::with_client(Recorder);
+        let registration = TournamentRegistration {
+            provider_id: 1022,
+            name: Some("Fixture Cup".into()),
+        };
+        testing::assert_requests(&[
+            (
+                api.register_provider(&ProviderRegistration::default()),
+                testing::send(
+                    Method::POST,
+                    Region::AMERICAS,
+                    "tournament-stub-v5.registerProviderData",
+                    format!("{}/providers", ENDPOINT),
+                ),
+            ),
+            (
+                api.register_tournament(&registration),
+                testing::send(
+                    Method::POST,
+                    Region::AMERICAS,
+                    "tournament-stub-v5.registerTournament",
+                    format!("{}/tournaments", ENDPOINT),
+                ),
+            ),
+            (
+                api.create_codes(5384, 1, &TournamentCodeParameters::default()),
+                testing::send(
+                    Method::POST,
+                    Region::AMERICAS,
+                    "tournament-stub-v5.createTournamentCode",
+                    format!("{}/codes?tournamentId=5384&count=1", ENDPOINT),
+                ),
+            ),
+            (
+                api.code(CODE),
+                testing::get(
+                    Region::AMERICAS,
+                    "tournament-stub-v5.getTournamentCode",
+                    format!("{}/codes/{}", ENDPOINT, CODE),
+                ),
+            ),
+            (
+                api.lobby_events(CODE),
+                testing::get(
+                    Region::AMERICAS,
+                    "tournament-stub-v5.getLobbyEventsByCode",
+                    format!("{}/lobby-events/by-code/{}", ENDPOINT, CODE),
+                ),
+            ),
+        ]);
+
+        let request = api.register_tournament(&registration);
+        let body: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(body, json!({"providerId": 1022, "name": "Fixture Cup"}));
+    }
+}
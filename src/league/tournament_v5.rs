@@ -0,0 +1,450 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    ids::{MatchId, Puuid},
+    Platform, Route,
+};
+pub use crate::{error::Error, Region};
+
+const ENDPOINT: &str = "/lol/tournament/v5";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PickType {
+    BlindPick,
+    DraftMode,
+    AllRandom,
+    #[default]
+    TournamentDraft,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MapType {
+    #[default]
+    SummonersRift,
+    HowlingAbyss,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SpectatorType {
+    None,
+    #[default]
+    LobbyOnly,
+    All,
+}
+
+/// Registers the URL Riot posts game results to.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderRegistration {
+    /// Riot's tournament region, e.g. `NA` or `EUW`, not a [`Platform`].
+    pub region: String,
+    /// Must use port 80 for http or 443 for https.
+    pub url: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentRegistration {
+    pub provider_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Settings for the lobbies created by a batch of tournament codes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCodeParameters {
+    /// Only these players may join; anyone may when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_participants: Option<Vec<Puuid>>,
+    /// Passed back in the game result callback.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    pub team_size: u32,
+    pub pick_type: PickType,
+    pub map_type: MapType,
+    pub spectator_type: SpectatorType,
+    /// Whether the game may start before every allowed participant joined.
+    pub enough_players: bool,
+}
+
+impl Default for TournamentCodeParameters {
+    fn default() -> Self {
+        Self {
+            allowed_participants: None,
+            metadata: None,
+            team_size: 5,
+            pick_type: PickType::default(),
+            map_type: MapType::default(),
+            spectator_type: SpectatorType::default(),
+            enough_players: false,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCodeUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_participants: Option<Vec<Puuid>>,
+    pub pick_type: PickType,
+    pub map_type: MapType,
+    pub spectator_type: SpectatorType,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentCode {
+    pub code: String,
+    pub spectators: SpectatorType,
+    pub lobby_name: String,
+    pub meta_data: Option<String>,
+    pub password: String,
+    pub team_size: u32,
+    pub provider_id: i64,
+    pub pick_type: PickType,
+    pub tournament_id: i64,
+    pub id: i64,
+    pub region: String,
+    pub map: MapType,
+    #[serde(default)]
+    pub participants: Vec<Puuid>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LobbyEvents {
+    pub event_list: Vec<LobbyEvent>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LobbyEvent {
+    /// Milliseconds since the Unix epoch, as a string.
+    pub timestamp: String,
+    /// E.g. `PlayerJoinedGameEvent` or `ChampSelectStartedEvent`.
+    pub event_type: String,
+    pub puuid: Option<Puuid>,
+}
+
+/// The result of a game played with a tournament code.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentGame {
+    pub winning_team: Vec<TournamentPlayer>,
+    pub losing_team: Vec<TournamentPlayer>,
+    pub short_code: String,
+    pub meta_data: Option<String>,
+    pub game_id: u64,
+    pub game_name: String,
+    pub game_type: String,
+    pub game_map: i64,
+    pub game_mode: String,
+    /// The platform the game was played on.
+    pub region: Platform,
+}
+
+impl TournamentGame {
+    pub fn match_id(&self) -> MatchId {
+        MatchId::new(self.region, self.game_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TournamentPlayer {
+    pub puuid: Puuid,
+}
+
+/// Riot's tournament API. It's only served from AMERICAS, whatever region the
+/// tournament is played in.
+#[derive(Debug, Clone)]
+pub struct TournamentV5<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl TournamentV5<RiotClient> {
+    pub fn new(key: &str) -> Self {
+        RiotClient::new(key).tournament_v5()
+    }
+}
+
+impl<C: Executor> TournamentV5<C> {
+    pub(crate) fn with_client(client: C) -> Self {
+        Self {
+            client,
+            route: Route::Region(Region::AMERICAS),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Returns the new provider's id.
+    pub fn register_provider(&self, registration: &ProviderRegistration) -> C::Output<i64> {
+        let path = format!("{}/providers", ENDPOINT);
+        self.client.execute(
+            ApiRequest::new(self.route, "tournament-v5.registerProviderData", path)
+                .with_json(Method::POST, registration),
+        )
+    }
+
+    /// Returns the new tournament's id.
+    pub fn register_tournament(&self, registration: &TournamentRegistration) -> C::Output<i64> {
+        let path = format!("{}/tournaments", ENDPOINT);
+        self.client.execute(
+            ApiRequest::new(self.route, "tournament-v5.registerTournament", path)
+                .with_json(Method::POST, registration),
+        )
+    }
+
+    /// Creates `count` codes, up to 1000, that all use `parameters`.
+    pub fn create_codes(
+        &self,
+        tournament_id: i64,
+        count: u32,
+        parameters: &TournamentCodeParameters,
+    ) -> C::Output<Vec<String>> {
+        let path = format!(
+            "{}/codes?tournamentId={}&count={}",
+            ENDPOINT, tournament_id, count
+        );
+        self.client.execute(
+            ApiRequest::new(self.route, "tournament-v5.createTournamentCode", path)
+                .with_json(Method::POST, parameters),
+        )
+    }
+
+    pub fn code(&self, code: &str) -> C::Output<TournamentCode> {
+        let path = format!("{}/codes/{}", ENDPOINT, code);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tournament-v5.getTournamentCode",
+            path,
+        ))
+    }
+
+    pub fn update_code(&self, code: &str, update: &TournamentCodeUpdate) -> C::Output<()> {
+        let path = format!("{}/codes/{}", ENDPOINT, code);
+        self.client.execute(
+            ApiRequest::new(self.route, "tournament-v5.updateCode", path)
+                .with_json(Method::PUT, update),
+        )
+    }
+
+    pub fn lobby_events(&self, code: &str) -> C::Output<LobbyEvents> {
+        let path = format!("{}/lobby-events/by-code/{}", ENDPOINT, code);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tournament-v5.getLobbyEventsByCode",
+            path,
+        ))
+    }
+
+    /// Results of the games played with `code`, once Riot has them.
+    pub fn games(&self, code: &str) -> C::Output<Vec<TournamentGame>> {
+        let path = format!("{}/games/by-code/{}", ENDPOINT, code);
+        self.client
+            .execute(ApiRequest::new(self.route, "tournament-v5.getGames", path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::testing::{self, Recorder, PUUID};
+
+    const CODE: &str = "NA04a8e-2f9c61d7-5b3e-4a10-8d2c-7e41b9f05a63";
+
+    fn json_body(request: &ApiRequest) -> Value {
+        serde_json::from_slice(request.body.as_deref().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_requests() {
+        let api = TournamentV5::with_client(Recorder);
+        testing::assert_requests(&[
+            (
+                api.register_provider(&ProviderRegistration::default()),
+                testing::send(
+                    Method::POST,
+                    Region::AMERICAS,
+                    "tournament-v5.registerProviderData",
+                    format!("{}/providers", ENDPOINT),
+                ),
+            ),
+            (
+                api.register_tournament(&TournamentRegistration::default()),
+                testing::send(
+                    Method::POST,
+                    Region::AMERICAS,
+                    "tournament-v5.registerTournament",
+                    format!("{}/tournaments", ENDPOINT),
+                ),
+            ),
+            (
+                api.create_codes(5384, 1, &TournamentCodeParameters::default()),
+                testing::send(
+                    Method::POST,
+                    Region::AMERICAS,
+                    "tournament-v5.createTournamentCode",
+                    format!("{}/codes?tournamentId=5384&count=1", ENDPOINT),
+                ),
+            ),
+            (
+                api.code(CODE),
+                testing::get(
+                    Region::AMERICAS,
+                    "tournament-v5.getTournamentCode",
+                    format!("{}/codes/{}", ENDPOINT, CODE),
+                ),
+            ),
+            (
+                api.update_code(CODE, &TournamentCodeUpdate::default()),
+                testing::send(
+                    Method::PUT,
+                    Region::AMERICAS,
+                    "tournament-v5.updateCode",
+                    format!("{}/codes/{}", ENDPOINT, CODE),
+                ),
+            ),
+            (
+                api.lobby_events(CODE),
+                testing::get(
+                    Region::AMERICAS,
+                    "tournament-v5.getLobbyEventsByCode",
+                    format!("{}/lobby-events/by-code/{}", ENDPOINT, CODE),
+                ),
+            ),
+            (
+                api.games(CODE),
+                testing::get(
+                    Region::AMERICAS,
+                    "tournament-v5.getGames",
+                    format!("{}/games/by-code/{}", ENDPOINT, CODE),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_request_bodies() {
+        let api = TournamentV5::with_client(Recorder);
+        let request = api.register_provider(&ProviderRegistration {
+            region: "NA".into(),
+            url: "https://example.com/callback".into(),
+        });
+        assert_eq!(request.http_method, Method::POST);
+        assert_eq!(
+            json_body(&request),
+            json!({"region": "NA", "url": "https://example.com/callback"})
+        );
+
+        let request = api.register_tournament(&TournamentRegistration {
+            provider_id: 1022,
+            name: None,
+        });
+        assert_eq!(json_body(&request), json!({"providerId": 1022}));
+
+        let parameters = TournamentCodeParameters {
+            allowed_participants: Some(vec![testing::puuid()]),
+            team_size: 1,
+            pick_type: PickType::BlindPick,
+            map_type: MapType::HowlingAbyss,
+            spectator_type: SpectatorType::All,
+            enough_players: true,
+            ..Default::default()
+        };
+        let request = api.create_codes(5384, 1, &parameters);
+        assert_eq!(request.http_method, Method::POST);
+        assert_eq!(
+            json_body(&request),
+            json!({
+                "allowedParticipants": [PUUID],
+                "teamSize": 1,
+                "pickType": "BLIND_PICK",
+                "mapType": "HOWLING_ABYSS",
+                "spectatorType": "ALL",
+                "enoughPlayers": true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let res: TournamentCode = testing::decode("tournament_v5/code.json");
+        assert_eq!(res.spectators, SpectatorType::LobbyOnly);
+        assert_eq!(res.pick_type, PickType::TournamentDraft);
+        assert_eq!(res.map, MapType::SummonersRift);
+        assert_eq!(res.participants[0], PUUID);
+
+        let res: LobbyEvents = testing::decode("tournament_v5/lobby_events.json");
+        assert_eq!(res.event_list.len(), 3);
+        assert_eq!(res.event_list[2].event_type, "ChampSelectStartedEvent");
+        assert_eq!(res.event_list[2].puuid, None);
+
+        let res: Vec<TournamentGame> = testing::decode("tournament_v5/games.json");
+        assert_eq!(res[0].winning_team[0].puuid, PUUID);
+        assert_eq!(res[0].match_id().to_string(), testing::MATCH_ID);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_update_code_sent_as_json() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("PUT", format!("{}/codes/{}", ENDPOINT, CODE).as_str())
+            .match_header("content-type", "application/json")
+            .match_body(mockito::Matcher::Json(json!({
+                "pickType": "TOURNAMENT_DRAFT",
+                "mapType": "SUMMONERS_RIFT",
+                "spectatorType": "NONE",
+            })))
+            .create();
+
+        let api = testing::client(&server).tournament_v5();
+        let update = TournamentCodeUpdate {
+            spectator_type: SpectatorType::None,
+            ..Default::default()
+        };
+        api.update_code(CODE, &update).unwrap();
+        mock.assert();
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_create_codes_not_retried() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock(
+                "POST",
+                format!("{}/codes?tournamentId=5384&count=1", ENDPOINT).as_str(),
+            )
+            .with_status(503)
+            .expect(1)
+            .create();
+
+        let client = RiotClient::builder("key")
+            .base_url(&server.url())
+            .build()
+            .unwrap();
+        let res =
+            client
+                .tournament_v5()
+                .create_codes(5384, 1, &TournamentCodeParameters::default());
+        assert!(res.is_err());
+        mock.assert();
+    }
+}
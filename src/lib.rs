@@ -1,38 +1,24 @@
+#[cfg(not(any(feature = "blocking", feature = "async")))]
+compile_error!("riot_api needs the `blocking` feature, the `async` feature, or both");
+
+pub mod cache;
+pub mod client;
 pub mod error;
+pub mod fixture;
+pub mod ids;
 pub mod league;
+pub mod rate_limit;
+pub mod retry;
+pub mod riot;
+pub mod routing;
+#[cfg(test)]
+mod testing;
+pub mod tft;
+pub mod transport;
 
-use std::fmt::Display;
-
-#[derive(Debug)]
-pub enum Platform {
-    BR1,
-    EUN1,
-    EUW1,
-    JP1,
-    KR,
-    LA1,
-    LA2,
-    NA1,
-    OC1,
-    TR1,
-    RU,
-}
-
-impl Display for Platform {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
-    }
-}
-
-#[derive(Debug)]
-pub enum Region {
-    AMERICAS,
-    ASIA,
-    EUROPE,
-}
-
-impl Display for Region {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
-    }
-}
+#[cfg(feature = "async")]
+pub use client::AsyncRiotClient;
+#[cfg(feature = "blocking")]
+pub use client::RiotClient;
+pub use client::RiotClientBuilder;
+pub use routing::{Platform, Region, Route};
@@ -0,0 +1,440 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use reqwest::header::HeaderMap;
+
+use crate::Route;
+
+pub const APP_RATE_LIMIT: &str = "X-App-Rate-Limit";
+pub const APP_RATE_LIMIT_COUNT: &str = "X-App-Rate-Limit-Count";
+pub const METHOD_RATE_LIMIT: &str = "X-Method-Rate-Limit";
+pub const METHOD_RATE_LIMIT_COUNT: &str = "X-Method-Rate-Limit-Count";
+pub const RATE_LIMIT_TYPE: &str = "X-Rate-Limit-Type";
+pub const RETRY_AFTER: &str = "Retry-After";
+
+/// Which limit a `429` was caused by, as reported in `X-Rate-Limit-Type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitType {
+    Application,
+    Method,
+    Service,
+}
+
+impl RateLimitType {
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        match headers.get(RATE_LIMIT_TYPE)?.to_str().ok()? {
+            "application" => Some(Self::Application),
+            "method" => Some(Self::Method),
+            "service" => Some(Self::Service),
+            _ => None,
+        }
+    }
+}
+
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds))
+}
+
+/// What the client does when a request would exceed a rate limit bucket.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Sleep until every bucket has room, then send the request.
+    #[default]
+    Block,
+    /// Return `Error::RateLimited` with the time left until the request could be sent.
+    Fail,
+    /// Send requests without any pacing.
+    Disabled,
+}
+
+/// The rate limit headers of a response, kept on errors for diagnostics.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitHeaders {
+    pub limit_type: Option<RateLimitType>,
+    pub retry_after: Option<Duration>,
+    pub app_limit: Option<String>,
+    pub app_count: Option<String>,
+    pub method_limit: Option<String>,
+    pub method_count: Option<String>,
+}
+
+impl RateLimitHeaders {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        Self {
+            limit_type: RateLimitType::from_headers(headers),
+            retry_after: retry_after(headers),
+            app_limit: header(APP_RATE_LIMIT),
+            app_count: header(APP_RATE_LIMIT_COUNT),
+            method_limit: header(METHOD_RATE_LIMIT),
+            method_count: header(METHOD_RATE_LIMIT_COUNT),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Bucket {
+    limit: u32,
+    window: Duration,
+    count: u32,
+    start: Instant,
+}
+
+impl Bucket {
+    fn new(limit: u32, window: Duration, now: Instant) -> Self {
+        Self {
+            limit,
+            window,
+            count: 0,
+            start: now,
+        }
+    }
+
+    fn refresh(&mut self, now: Instant) {
+        if now.duration_since(self.start) >= self.window {
+            self.count = 0;
+            self.start = now;
+        }
+    }
+
+    fn wait_time(&mut self, now: Instant) -> Option<Duration> {
+        self.refresh(now);
+        if self.count < self.limit {
+            return None;
+        }
+        Some(self.window - now.duration_since(self.start))
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    app: HashMap<Route, Vec<Bucket>>,
+    method: HashMap<(Route, &'static str), Vec<Bucket>>,
+    app_blocked: HashMap<Route, Instant>,
+    method_blocked: HashMap<(Route, &'static str), Instant>,
+}
+
+impl State {
+    fn blocked(&self, route: Route, method: &'static str, now: Instant) -> Option<Duration> {
+        self.app_blocked
+            .get(&route)
+            .into_iter()
+            .chain(self.method_blocked.get(&(route, method)))
+            .filter(|until| **until > now)
+            .map(|until| *until - now)
+            .max()
+    }
+}
+
+/// Tracks the application and method rate limits Riot reports for each
+/// routing value, so requests can be paced before they would be rejected.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns how long a request to `method` on `route` has to wait, without
+    /// reserving a slot for it.
+    pub fn wait_time(&self, route: Route, method: &'static str) -> Option<Duration> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        let blocked = state.blocked(route, method, now);
+        let State {
+            app,
+            method: methods,
+            ..
+        } = &mut *state;
+        app.get_mut(&route)
+            .into_iter()
+            .chain(methods.get_mut(&(route, method)))
+            .flat_map(|buckets| buckets.iter_mut())
+            .filter_map(|bucket| bucket.wait_time(now))
+            .chain(blocked)
+            .max()
+    }
+
+    /// Reserves a slot in every bucket for `method` on `route`. When any bucket
+    /// is full nothing is reserved and the time until it frees up is returned.
+    pub fn acquire(&self, route: Route, method: &'static str) -> Option<Duration> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        if let Some(wait) = state.blocked(route, method, now) {
+            return Some(wait);
+        }
+        let State {
+            app,
+            method: methods,
+            ..
+        } = &mut *state;
+        let mut buckets: Vec<&mut Bucket> = app
+            .get_mut(&route)
+            .into_iter()
+            .chain(methods.get_mut(&(route, method)))
+            .flat_map(|buckets| buckets.iter_mut())
+            .collect();
+
+        let wait = buckets
+            .iter_mut()
+            .filter_map(|bucket| bucket.wait_time(now))
+            .max();
+        if wait.is_none() {
+            buckets.iter_mut().for_each(|bucket| bucket.count += 1);
+        }
+        wait
+    }
+
+    /// Syncs the buckets for `method` on `route` with the limits and counts
+    /// reported in a response's headers.
+    pub fn update(&self, route: Route, method: &'static str, headers: &HeaderMap) {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        if let Some(limits) = header_pairs(headers, APP_RATE_LIMIT) {
+            let counts = header_pairs(headers, APP_RATE_LIMIT_COUNT).unwrap_or_default();
+            let buckets = state.app.entry(route).or_default();
+            sync_buckets(buckets, &limits, &counts, now);
+        }
+        if let Some(limits) = header_pairs(headers, METHOD_RATE_LIMIT) {
+            let counts = header_pairs(headers, METHOD_RATE_LIMIT_COUNT).unwrap_or_default();
+            let buckets = state.method.entry((route, method)).or_default();
+            sync_buckets(buckets, &limits, &counts, now);
+        }
+    }
+
+    /// Holds back every request sharing the exhausted bucket of a `429` until
+    /// its `Retry-After` has passed. Service limits are not tracked since they
+    /// are shared with every other application.
+    pub fn on_rate_limited(&self, route: Route, method: &'static str, headers: &HeaderMap) {
+        let Some(wait) = retry_after(headers) else {
+            return;
+        };
+        let until = Instant::now() + wait;
+        let mut state = self.state.lock().unwrap();
+        match RateLimitType::from_headers(headers) {
+            Some(RateLimitType::Application) => {
+                state.app_blocked.insert(route, until);
+            }
+            Some(RateLimitType::Method) => {
+                state.method_blocked.insert((route, method), until);
+            }
+            Some(RateLimitType::Service) | None => {}
+        }
+    }
+}
+
+fn sync_buckets(
+    buckets: &mut Vec<Bucket>,
+    limits: &[(u32, u64)],
+    counts: &[(u32, u64)],
+    now: Instant,
+) {
+    buckets.retain(|bucket| {
+        limits
+            .iter()
+            .any(|(_, window)| bucket.window == Duration::from_secs(*window))
+    });
+    for (limit, window) in limits {
+        let window = Duration::from_secs(*window);
+        let index = match buckets.iter().position(|bucket| bucket.window == window) {
+            Some(index) => index,
+            None => {
+                buckets.push(Bucket::new(*limit, window, now));
+                buckets.len() - 1
+            }
+        };
+        let bucket = &mut buckets[index];
+        bucket.refresh(now);
+        bucket.limit = *limit;
+        if let Some((count, _)) = counts
+            .iter()
+            .find(|(_, count_window)| Duration::from_secs(*count_window) == window)
+        {
+            bucket.count = bucket.count.max(*count);
+        }
+    }
+}
+
+fn header_pairs(headers: &HeaderMap, name: &str) -> Option<Vec<(u32, u64)>> {
+    let value = headers.get(name)?.to_str().ok()?;
+    Some(parse_pairs(value))
+}
+
+/// Parses a rate limit header such as `20:1,100:120` into `(value, seconds)` pairs.
+pub fn parse_pairs(value: &str) -> Vec<(u32, u64)> {
+    value
+        .split(',')
+        .filter_map(|pair| {
+            let (value, window) = pair.trim().split_once(':')?;
+            Some((value.parse().ok()?, window.parse().ok()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+    use crate::{Platform, Region};
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn test_parse_pairs() {
+        assert_eq!(parse_pairs("20:1,100:120"), vec![(20, 1), (100, 120)]);
+        assert_eq!(parse_pairs(" 1:1 , bad, 3:10"), vec![(1, 1), (3, 10)]);
+        assert!(parse_pairs("").is_empty());
+    }
+
+    #[test]
+    fn test_app_limit() {
+        let limiter = RateLimiter::new();
+        let route = Route::from(Platform::NA1);
+        limiter.update(
+            route,
+            "summoner-v4.getByPUUID",
+            &headers(&[(APP_RATE_LIMIT, "2:10"), (APP_RATE_LIMIT_COUNT, "1:10")]),
+        );
+
+        assert_eq!(limiter.acquire(route, "summoner-v4.getByPUUID"), None);
+        let wait = limiter.acquire(route, "match-v5.getMatch").unwrap();
+        assert!(wait > Duration::from_secs(9) && wait <= Duration::from_secs(10));
+        assert_eq!(
+            limiter.acquire(Platform::EUW1.into(), "summoner-v4.getByPUUID"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_method_limit() {
+        let limiter = RateLimiter::new();
+        let route = Route::from(Region::AMERICAS);
+        limiter.update(
+            route,
+            "match-v5.getMatch",
+            &headers(&[
+                (APP_RATE_LIMIT, "100:1"),
+                (APP_RATE_LIMIT_COUNT, "1:1"),
+                (METHOD_RATE_LIMIT, "1:60"),
+                (METHOD_RATE_LIMIT_COUNT, "1:60"),
+            ]),
+        );
+
+        assert!(limiter.wait_time(route, "match-v5.getMatch").is_some());
+        assert!(limiter.acquire(route, "match-v5.getMatch").is_some());
+        assert_eq!(limiter.acquire(route, "match-v5.getTimeline"), None);
+    }
+
+    #[test]
+    fn test_update_replaces_windows() {
+        let limiter = RateLimiter::new();
+        let route = Route::from(Platform::KR);
+        limiter.update(
+            route,
+            "summoner-v4.getByPUUID",
+            &headers(&[(APP_RATE_LIMIT, "1:10"), (APP_RATE_LIMIT_COUNT, "1:10")]),
+        );
+        assert!(limiter.wait_time(route, "summoner-v4.getByPUUID").is_some());
+
+        limiter.update(
+            route,
+            "summoner-v4.getByPUUID",
+            &headers(&[(APP_RATE_LIMIT, "5:1"), (APP_RATE_LIMIT_COUNT, "1:1")]),
+        );
+        assert_eq!(limiter.wait_time(route, "summoner-v4.getByPUUID"), None);
+    }
+
+    #[test]
+    fn test_on_rate_limited() {
+        let limiter = RateLimiter::new();
+        let route = Route::from(Platform::NA1);
+        limiter.on_rate_limited(
+            route,
+            "match-v5.getMatch",
+            &headers(&[(RATE_LIMIT_TYPE, "method"), (RETRY_AFTER, "5")]),
+        );
+        assert!(limiter.acquire(route, "match-v5.getMatch").is_some());
+        assert_eq!(limiter.acquire(route, "match-v5.getTimeline"), None);
+
+        limiter.on_rate_limited(
+            route,
+            "match-v5.getTimeline",
+            &headers(&[(RATE_LIMIT_TYPE, "application"), (RETRY_AFTER, "5")]),
+        );
+        assert!(limiter.wait_time(route, "match-v5.getTimeline").is_some());
+        assert!(limiter.wait_time(route, "summoner-v4.getByPUUID").is_some());
+
+        limiter.on_rate_limited(
+            Platform::KR.into(),
+            "match-v5.getMatch",
+            &headers(&[(RATE_LIMIT_TYPE, "service"), (RETRY_AFTER, "5")]),
+        );
+        assert_eq!(
+            limiter.wait_time(Platform::KR.into(), "match-v5.getMatch"),
+            None
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_client_against_mock_server() {
+        use crate::{error::Error, testing, RiotClient};
+
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", testing::PUUID).as_str(),
+            )
+            .with_header(APP_RATE_LIMIT, "100:1,2:120")
+            .with_header(APP_RATE_LIMIT_COUNT, "1:1,1:120")
+            .with_header(METHOD_RATE_LIMIT, "2000:60")
+            .with_header(METHOD_RATE_LIMIT_COUNT, "1:60")
+            .with_body(r#"{"id":"id","accountId":"account","puuid":"puuid","name":"name","profileIconId":1,"revisionDate":1,"summonerLevel":1}"#)
+            .expect(2)
+            .create();
+
+        let client = RiotClient::builder("key")
+            .base_url(&server.url())
+            .rate_limit_mode(RateLimitMode::Fail)
+            .build()
+            .unwrap();
+        let api = client.summoner_v4(Platform::NA1);
+
+        api.by_puuid(&testing::puuid()).unwrap();
+        api.by_puuid(&testing::puuid()).unwrap();
+        match api.by_puuid(&testing::puuid()) {
+            Err(Error::RateLimited { context, wait }) => {
+                assert_eq!(context.method, "summoner-v4.getByPUUID");
+                assert!(wait > Duration::from_secs(100));
+            }
+            other => panic!("expected rate limit error, got {:?}", other),
+        }
+        mock.assert();
+    }
+}
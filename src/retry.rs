@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use reqwest::{header::HeaderMap, StatusCode};
+
+use crate::rate_limit::{retry_after, RateLimitType};
+
+/// Controls how failed requests are retried. A `429` is retried after its
+/// `Retry-After` delay when Riot sends one, and with exponential backoff
+/// otherwise; `500`, `502`, `503` and `504` are retried with backoff, except
+/// for `POST`s, which only retry on `429`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Upper bound on the time spent across all attempts and delays.
+    pub max_elapsed: Duration,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Whether `429`s caused by Riot's service limits, rather than this
+    /// application's limits, are retried.
+    pub retry_service_rate_limits: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            max_elapsed: Duration::from_secs(60),
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            retry_service_rate_limits: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Returns how long to wait before retrying a request that got `status`
+    /// back, or `None` when it should not be retried. `attempt` is the number
+    /// of attempts made so far and `elapsed` the time since the first one.
+    pub fn delay(
+        &self,
+        attempt: u32,
+        elapsed: Duration,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let delay = match status {
+            StatusCode::TOO_MANY_REQUESTS => {
+                let limit_type = RateLimitType::from_headers(headers);
+                if limit_type == Some(RateLimitType::Service) && !self.retry_service_rate_limits {
+                    return None;
+                }
+                retry_after(headers).unwrap_or_else(|| self.backoff(attempt))
+            }
+            StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT => self.backoff(attempt),
+            _ => return None,
+        };
+
+        if elapsed + delay > self.max_elapsed {
+            return None;
+        }
+        Some(delay)
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        delay / 2 + delay.mul_f64(fastrand::f64() / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+    use crate::rate_limit::{RATE_LIMIT_TYPE, RETRY_AFTER};
+    #[cfg(feature = "blocking")]
+    use crate::{
+        error::Error,
+        rate_limit::{RateLimitMode, RateLimitType},
+        testing, Platform, RiotClient,
+    };
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let policy = RetryPolicy::default();
+        let headers = headers(&[(RETRY_AFTER, "7"), (RATE_LIMIT_TYPE, "application")]);
+        assert_eq!(
+            policy.delay(1, Duration::ZERO, StatusCode::TOO_MANY_REQUESTS, &headers),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(
+            policy.delay(
+                1,
+                Duration::from_secs(55),
+                StatusCode::TOO_MANY_REQUESTS,
+                &headers
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_service_rate_limit() {
+        let policy = RetryPolicy {
+            retry_service_rate_limits: false,
+            ..RetryPolicy::default()
+        };
+        let service = headers(&[(RATE_LIMIT_TYPE, "service")]);
+        let method = headers(&[(RATE_LIMIT_TYPE, "method"), (RETRY_AFTER, "1")]);
+        assert_eq!(
+            policy.delay(1, Duration::ZERO, StatusCode::TOO_MANY_REQUESTS, &service),
+            None
+        );
+        assert_eq!(
+            policy.delay(1, Duration::ZERO, StatusCode::TOO_MANY_REQUESTS, &method),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(800),
+            ..RetryPolicy::default()
+        };
+        let empty = HeaderMap::new();
+        for (attempt, full) in [(1, 100), (2, 200), (3, 400), (4, 800), (9, 800)] {
+            let delay = policy
+                .delay(attempt, Duration::ZERO, StatusCode::BAD_GATEWAY, &empty)
+                .unwrap();
+            assert!(delay >= Duration::from_millis(full / 2));
+            assert!(delay <= Duration::from_millis(full));
+        }
+        assert_eq!(
+            policy.delay(10, Duration::ZERO, StatusCode::BAD_GATEWAY, &empty),
+            None
+        );
+        assert_eq!(
+            policy.delay(1, Duration::ZERO, StatusCode::NOT_FOUND, &empty),
+            None
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_client_retries() {
+        let mut server = mockito::Server::new();
+        let unavailable = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", testing::PUUID).as_str(),
+            )
+            .with_status(503)
+            .expect(2)
+            .create();
+        let ok = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", testing::PUUID).as_str(),
+            )
+            .with_body(r#"{"id":"id","accountId":"account","puuid":"puuid","name":"name","profileIconId":1,"revisionDate":1,"summonerLevel":1}"#)
+            .create();
+
+        let client = RiotClient::builder("key")
+            .base_url(&server.url())
+            .retry_policy(RetryPolicy {
+                base_delay: Duration::from_millis(1),
+                ..RetryPolicy::default()
+            })
+            .build()
+            .unwrap();
+        let res = client
+            .summoner_v4(Platform::NA1)
+            .by_puuid(&testing::puuid())
+            .unwrap();
+        assert_eq!(res.puuid, "puuid");
+        unavailable.assert();
+        ok.assert();
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_client_gives_up() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock(
+                "GET",
+                format!("/lol/summoner/v4/summoners/by-puuid/{}", testing::PUUID).as_str(),
+            )
+            .with_status(429)
+            .with_header(RATE_LIMIT_TYPE, "method")
+            .with_header(RETRY_AFTER, "0")
+            .expect(2)
+            .create();
+
+        let client = RiotClient::builder("key")
+            .base_url(&server.url())
+            .rate_limit_mode(RateLimitMode::Disabled)
+            .retry_policy(RetryPolicy {
+                max_attempts: 2,
+                ..RetryPolicy::default()
+            })
+            .build()
+            .unwrap();
+        match client
+            .summoner_v4(Platform::NA1)
+            .by_puuid(&testing::puuid())
+        {
+            Err(Error::Status(error)) => {
+                assert_eq!(error.status, StatusCode::TOO_MANY_REQUESTS);
+                assert_eq!(error.rate_limit.limit_type, Some(RateLimitType::Method));
+            }
+            other => panic!("expected status error, got {:?}", other),
+        }
+        mock.assert();
+    }
+}
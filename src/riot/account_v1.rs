@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    ids::{Puuid, RiotId},
+    Route,
+};
+pub use crate::{error::Error, Region};
+
+const ENDPOINT: &str = "/riot/account/v1";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Account {
+    pub puuid: Puuid,
+    pub game_name: Option<String>,
+    pub tag_line: Option<String>,
+}
+
+impl Account {
+    pub fn riot_id(&self) -> Option<RiotId> {
+        Some(RiotId::new(
+            self.game_name.as_ref()?,
+            self.tag_line.as_ref()?,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveShard {
+    pub puuid: Puuid,
+    pub game: String,
+    /// The game's own shard name, e.g. `na` or `latam`, not a [`Platform`](crate::Platform).
+    pub active_shard: String,
+}
+
+/// Games whose active shard can be looked up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Game {
+    Valorant,
+    LegendsOfRuneterra,
+}
+
+impl Game {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Valorant => "val",
+            Self::LegendsOfRuneterra => "lor",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountV1<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl AccountV1<RiotClient> {
+    pub fn new(key: &str, region: impl Into<Region>) -> Self {
+        RiotClient::new(key).account_v1(region)
+    }
+}
+
+impl<C: Executor> AccountV1<C> {
+    /// Accounts are global, so any region finds any account. Account-V1 isn't
+    /// served from SEA, so requests for it go to ASIA instead.
+    pub(crate) fn with_client(client: C, region: impl Into<Region>) -> Self {
+        let region = match region.into() {
+            Region::SEA => Region::ASIA,
+            region => region,
+        };
+        Self {
+            client,
+            route: Route::Region(region),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn by_puuid(&self, puuid: &Puuid) -> C::Output<Account> {
+        let path = format!("{}/accounts/by-puuid/{}", ENDPOINT, puuid);
+        self.client
+            .execute(ApiRequest::new(self.route, "account-v1.getByPuuid", path))
+    }
+
+    pub fn by_riot_id(&self, riot_id: &RiotId) -> C::Output<Account> {
+        let path = format!(
+            "{}/accounts/by-riot-id/{}/{}",
+            ENDPOINT, riot_id.game_name, riot_id.tag_line
+        );
+        self.client
+            .execute(ApiRequest::new(self.route, "account-v1.getByRiotId", path))
+    }
+
+    pub fn active_shard(&self, game: Game, puuid: &Puuid) -> C::Output<ActiveShard> {
+        let path = format!(
+            "{}/active-shards/by-game/{}/by-puuid/{}",
+            ENDPOINT,
+            game.as_str(),
+            puuid
+        );
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "account-v1.getActiveShard",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{self, Recorder, PUUID},
+        Platform,
+    };
+
+    #[test]
+    fn test_requests() {
+        let api = AccountV1::with_client(Recorder, Region::AMERICAS);
+        testing::assert_requests(&[
+            (
+                api.by_puuid(&testing::puuid()),
+                testing::get(
+                    Region::AMERICAS,
+                    "account-v1.getByPuuid",
+                    format!("{}/accounts/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.by_riot_id(&"Fixture Summoner#NA1".parse().unwrap()),
+                testing::get(
+                    Region::AMERICAS,
+                    "account-v1.getByRiotId",
+                    format!("{}/accounts/by-riot-id/Fixture Summoner/NA1", ENDPOINT),
+                ),
+            ),
+            (
+                api.active_shard(Game::Valorant, &testing::puuid()),
+                testing::get(
+                    Region::AMERICAS,
+                    "account-v1.getActiveShard",
+                    format!("{}/active-shards/by-game/val/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_decode() {
+        let res: Account = testing::decode("account_v1/account.json");
+        assert_eq!(res.puuid, PUUID);
+        assert_eq!(res.riot_id(), Some(RiotId::new("Fixture Summoner", "NA1")));
+
+        let res: ActiveShard = testing::decode("account_v1/active_shard.json");
+        assert_eq!(res.game, "val");
+        assert_eq!(res.active_shard, "na");
+    }
+
+    #[test]
+    fn test_route() {
+        let api = AccountV1::with_client(Recorder, Platform::VN2);
+        assert_eq!(api.route, Route::Region(Region::ASIA));
+        let api = AccountV1::with_client(Recorder, Platform::EUW1);
+        assert_eq!(api.route, Route::Region(Region::EUROPE));
+    }
+}
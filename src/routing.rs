@@ -0,0 +1,339 @@
+use std::{error::Error as StdError, fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A platform routing value, i.e. the shard a summoner's account lives on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    BR1,
+    EUN1,
+    EUW1,
+    JP1,
+    KR,
+    LA1,
+    LA2,
+    NA1,
+    OC1,
+    TR1,
+    RU,
+    PH2,
+    SG2,
+    TH2,
+    TW2,
+    VN2,
+    ME1,
+}
+
+impl Platform {
+    pub const ALL: [Platform; 17] = [
+        Self::BR1,
+        Self::EUN1,
+        Self::EUW1,
+        Self::JP1,
+        Self::KR,
+        Self::LA1,
+        Self::LA2,
+        Self::NA1,
+        Self::OC1,
+        Self::TR1,
+        Self::RU,
+        Self::PH2,
+        Self::SG2,
+        Self::TH2,
+        Self::TW2,
+        Self::VN2,
+        Self::ME1,
+    ];
+
+    /// The regional route serving this platform's matches and accounts.
+    pub fn region(self) -> Region {
+        match self {
+            Self::BR1 | Self::LA1 | Self::LA2 | Self::NA1 => Region::AMERICAS,
+            Self::JP1 | Self::KR => Region::ASIA,
+            Self::EUN1 | Self::EUW1 | Self::TR1 | Self::RU | Self::ME1 => Region::EUROPE,
+            Self::OC1 | Self::PH2 | Self::SG2 | Self::TH2 | Self::TW2 | Self::VN2 => Region::SEA,
+        }
+    }
+
+    /// The lowercase routing value used in host names, e.g. `na1`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::BR1 => "br1",
+            Self::EUN1 => "eun1",
+            Self::EUW1 => "euw1",
+            Self::JP1 => "jp1",
+            Self::KR => "kr",
+            Self::LA1 => "la1",
+            Self::LA2 => "la2",
+            Self::NA1 => "na1",
+            Self::OC1 => "oc1",
+            Self::TR1 => "tr1",
+            Self::RU => "ru",
+            Self::PH2 => "ph2",
+            Self::SG2 => "sg2",
+            Self::TH2 => "th2",
+            Self::TW2 => "tw2",
+            Self::VN2 => "vn2",
+            Self::ME1 => "me1",
+        }
+    }
+
+    /// The platform a match was played on, from the prefix of its id, e.g.
+    /// `NA1` in `NA1_4123456789`.
+    pub fn from_match_id(match_id: &str) -> Option<Self> {
+        let (prefix, _) = match_id.split_once('_')?;
+        prefix.parse().ok()
+    }
+
+    /// e.g. `na1.api.riotgames.com`.
+    pub fn host(self) -> String {
+        format!("{}.api.riotgames.com", self.as_str())
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for Platform {
+    type Err = ParseRouteError;
+
+    /// Parses a routing value in any case, e.g. `na1` or `NA1`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|platform| platform.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseRouteError::new("platform", s))
+    }
+}
+
+impl TryFrom<&str> for Platform {
+    type Error = ParseRouteError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Serialized as the platform id in responses, e.g. `NA1`, and parsed in any
+/// case.
+impl Serialize for Platform {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Platform {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+/// A regional routing value, serving matches and accounts for several platforms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    AMERICAS,
+    ASIA,
+    EUROPE,
+    SEA,
+}
+
+impl Region {
+    pub const ALL: [Region; 4] = [Self::AMERICAS, Self::ASIA, Self::EUROPE, Self::SEA];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::AMERICAS => "americas",
+            Self::ASIA => "asia",
+            Self::EUROPE => "europe",
+            Self::SEA => "sea",
+        }
+    }
+
+    /// e.g. `americas.api.riotgames.com`.
+    pub fn host(self) -> String {
+        format!("{}.api.riotgames.com", self.as_str())
+    }
+
+    pub fn platforms(self) -> impl Iterator<Item = Platform> {
+        Platform::ALL
+            .into_iter()
+            .filter(move |platform| platform.region() == self)
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for Region {
+    type Err = ParseRouteError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|region| region.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ParseRouteError::new("region", s))
+    }
+}
+
+impl TryFrom<&str> for Region {
+    type Error = ParseRouteError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Lets regional endpoints be reached from the platform a summoner was found on.
+impl From<Platform> for Region {
+    fn from(platform: Platform) -> Self {
+        platform.region()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Route {
+    Platform(Platform),
+    Region(Region),
+}
+
+impl Route {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Platform(platform) => platform.as_str(),
+            Self::Region(region) => region.as_str(),
+        }
+    }
+}
+
+impl From<Platform> for Route {
+    fn from(platform: Platform) -> Self {
+        Self::Platform(platform)
+    }
+}
+
+impl From<Region> for Route {
+    fn from(region: Region) -> Self {
+        Self::Region(region)
+    }
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRouteError {
+    kind: &'static str,
+    value: String,
+}
+
+impl ParseRouteError {
+    fn new(kind: &'static str, value: &str) -> Self {
+        Self {
+            kind,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseRouteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} `{}`", self.kind, self.value)
+    }
+}
+
+impl StdError for ParseRouteError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region() {
+        assert_eq!(Platform::NA1.region(), Region::AMERICAS);
+        assert_eq!(Platform::KR.region(), Region::ASIA);
+        assert_eq!(Platform::TR1.region(), Region::EUROPE);
+        assert_eq!(Platform::ME1.region(), Region::EUROPE);
+        assert_eq!(Platform::OC1.region(), Region::SEA);
+        assert_eq!(
+            Region::SEA.platforms().collect::<Vec<_>>(),
+            [
+                Platform::OC1,
+                Platform::PH2,
+                Platform::SG2,
+                Platform::TH2,
+                Platform::TW2,
+                Platform::VN2
+            ]
+        );
+        assert_eq!(Region::from(Platform::EUW1), Region::EUROPE);
+        assert_eq!(
+            Region::ASIA.platforms().collect::<Vec<_>>(),
+            [Platform::JP1, Platform::KR]
+        );
+        for platform in Platform::ALL {
+            assert!(platform.region().platforms().any(|p| p == platform));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("na1".parse(), Ok(Platform::NA1));
+        assert_eq!("EuW1".parse(), Ok(Platform::EUW1));
+        assert_eq!(Platform::try_from("RU"), Ok(Platform::RU));
+        assert_eq!("Americas".parse(), Ok(Region::AMERICAS));
+        assert_eq!(Region::try_from("europe"), Ok(Region::EUROPE));
+
+        let error = "na".parse::<Platform>().unwrap_err();
+        assert_eq!(error.to_string(), "unknown platform `na`");
+        assert_eq!("SEA".parse(), Ok(Region::SEA));
+        assert!("oce".parse::<Region>().is_err());
+
+        for platform in Platform::ALL {
+            assert_eq!(platform.to_string().parse(), Ok(platform));
+        }
+    }
+
+    #[test]
+    fn test_platform_serde() {
+        assert_eq!(serde_json::to_string(&Platform::EUW1).unwrap(), r#""EUW1""#);
+        assert_eq!(
+            serde_json::from_str::<Platform>(r#""na1""#).unwrap(),
+            Platform::NA1
+        );
+        let error = serde_json::from_str::<Platform>(r#""XX9""#).unwrap_err();
+        assert!(error.to_string().starts_with("unknown platform `XX9`"));
+    }
+
+    #[test]
+    fn test_from_match_id() {
+        assert_eq!(
+            Platform::from_match_id("NA1_4123456789"),
+            Some(Platform::NA1)
+        );
+        assert_eq!(
+            Platform::from_match_id("VN2_712345678"),
+            Some(Platform::VN2)
+        );
+        assert_eq!(Platform::from_match_id("SG2_98765"), Some(Platform::SG2));
+        assert_eq!(Platform::from_match_id("XX9_1"), None);
+        assert_eq!(Platform::from_match_id("4123456789"), None);
+    }
+
+    #[test]
+    fn test_host() {
+        assert_eq!(Platform::EUN1.host(), "eun1.api.riotgames.com");
+        assert_eq!(Region::AMERICAS.host(), "americas.api.riotgames.com");
+        assert_eq!(Route::from(Platform::KR).to_string(), "kr");
+        assert_eq!(Route::from(Region::ASIA).to_string(), "asia");
+    }
+}
@@ -0,0 +1,137 @@
+// Most tests drive the blocking client, so async-only builds leave some of this unused.
+#![cfg_attr(not(feature = "blocking"), allow(dead_code))]
+
+use std::{fs, path::Path};
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "async")]
+use crate::AsyncRiotClient;
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    error::DecodeError,
+    ids::Puuid,
+    retry::RetryPolicy,
+    Route,
+};
+
+pub const PUUID: &str =
+    "Hk2mX0Qy6Nc4oXGWW3s_8yJ1fZkEoT5Vv1YkS2xqM9dZ0lP7rBnC3aF6uWq4eRtY8iOp1jKl2zXc5v";
+pub const SUMMONER_ID: &str = "sN7Qd2kL4pX9vB1mC8rT5yW3zF6hJ0gA-eU1iO4aS7dF2gH";
+pub const ACCOUNT_ID: &str = "aC9mZ2xV5bN8qW1eR4tY7uI0oP3lK6jH-gF9dS2aQ5wE8r";
+pub const SUMMONER_NAME: &str = "Fixture Summoner";
+pub const MATCH_ID: &str = "NA1_4123456789";
+
+/// Reads a recorded response from `tests/fixtures`.
+pub fn fixture(name: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name);
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path.display(), err))
+}
+
+/// Decodes a fixture the way the clients decode response bodies.
+pub fn decode<T: DeserializeOwned>(name: &str) -> T {
+    DecodeError::from_slice(fixture(name).as_bytes(), false)
+        .unwrap_or_else(|err| panic!("{}: {}: {}", name, err, err.source))
+}
+
+/// Hands each request back instead of sending it, so tests can check what an
+/// endpoint builds without a server.
+#[derive(Debug, Clone, Copy)]
+pub struct Recorder;
+
+impl Executor for Recorder {
+    type Output<T: DeserializeOwned + Send + 'static> = ApiRequest;
+
+    fn execute<T: DeserializeOwned + Send + 'static>(&self, request: ApiRequest) -> ApiRequest {
+        request
+    }
+
+    fn execute_optional<T: DeserializeOwned + Send + 'static>(
+        &self,
+        request: ApiRequest,
+    ) -> ApiRequest {
+        request
+    }
+}
+
+/// What a recorded request should look like.
+#[derive(Debug)]
+pub struct Expected {
+    route: Route,
+    http_method: Method,
+    method: &'static str,
+    path: String,
+}
+
+/// Expects a bodiless `GET` of `path` sent to `route`.
+pub fn get(route: impl Into<Route>, method: &'static str, path: String) -> Expected {
+    send(Method::GET, route, method, path)
+}
+
+/// Expects an `http_method` request of `path` sent to `route`.
+pub fn send(
+    http_method: Method,
+    route: impl Into<Route>,
+    method: &'static str,
+    path: String,
+) -> Expected {
+    Expected {
+        route: route.into(),
+        http_method,
+        method,
+        path,
+    }
+}
+
+/// Checks the route, HTTP method, Riot method id and path of each recorded
+/// request, and that `GET`s carry no body.
+pub fn assert_requests(cases: &[(ApiRequest, Expected)]) {
+    for (request, expected) in cases {
+        assert_eq!(
+            (
+                request.route,
+                &request.http_method,
+                request.method,
+                request.path.as_str()
+            ),
+            (
+                expected.route,
+                &expected.http_method,
+                expected.method,
+                expected.path.as_str()
+            )
+        );
+        if request.http_method == Method::GET {
+            assert_eq!(request.body, None, "{}", request.method);
+        }
+    }
+}
+
+/// A client pointed at a local mock server that fails fast instead of retrying.
+#[cfg(feature = "blocking")]
+pub fn client(server: &mockito::Server) -> RiotClient {
+    RiotClient::builder("key")
+        .base_url(&server.url())
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .unwrap()
+}
+
+/// The async counterpart of `client`.
+#[cfg(feature = "async")]
+pub fn async_client(server: &mockito::Server) -> AsyncRiotClient {
+    AsyncRiotClient::builder("key")
+        .base_url(&server.url())
+        .retry_policy(RetryPolicy::none())
+        .build_async()
+        .unwrap()
+}
+
+pub fn puuid() -> Puuid {
+    PUUID.parse().unwrap()
+}
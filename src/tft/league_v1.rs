@@ -0,0 +1,359 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    ids::{Puuid, SummonerId},
+    Route,
+};
+pub use crate::{
+    error::Error,
+    league::league_v4::{Division, LeagueItem, MiniSeries, Tier},
+    Platform,
+};
+
+const ENDPOINT: &str = "/tft/league/v1";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TftQueue {
+    Ranked,
+    /// Hyper Roll, ranked by [`RatedTier`] rather than tier and division.
+    Turbo,
+    DoubleUp,
+    /// An unknown queue, kept like [`RankedQueue::Other`](crate::league::league_v4::RankedQueue::Other).
+    Other(String),
+}
+
+impl TftQueue {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Ranked => "RANKED_TFT",
+            Self::Turbo => "RANKED_TFT_TURBO",
+            Self::DoubleUp => "RANKED_TFT_DOUBLE_UP",
+            Self::Other(queue) => queue,
+        }
+    }
+}
+
+impl fmt::Display for TftQueue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<String> for TftQueue {
+    fn from(queue: String) -> Self {
+        match queue.as_str() {
+            "RANKED_TFT" => Self::Ranked,
+            "RANKED_TFT_TURBO" => Self::Turbo,
+            "RANKED_TFT_DOUBLE_UP" => Self::DoubleUp,
+            _ => Self::Other(queue),
+        }
+    }
+}
+
+impl From<TftQueue> for String {
+    fn from(queue: TftQueue) -> Self {
+        match queue {
+            TftQueue::Other(queue) => queue,
+            queue => queue.as_str().to_string(),
+        }
+    }
+}
+
+/// Hyper Roll tiers, lowest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum RatedTier {
+    Gray,
+    Green,
+    Blue,
+    Purple,
+    Orange,
+}
+
+/// A player's standing in one TFT queue. Hyper Roll entries have a rated tier
+/// and rating instead of a tier, division and league points.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TftLeagueEntry {
+    pub puuid: Option<Puuid>,
+    pub league_id: Option<String>,
+    pub summoner_id: Option<SummonerId>,
+    pub queue_type: TftQueue,
+    pub tier: Option<Tier>,
+    pub rank: Option<Division>,
+    pub league_points: Option<u32>,
+    pub wins: u32,
+    pub losses: u32,
+    #[serde(default)]
+    pub veteran: bool,
+    #[serde(default)]
+    pub inactive: bool,
+    #[serde(default)]
+    pub fresh_blood: bool,
+    #[serde(default)]
+    pub hot_streak: bool,
+    pub mini_series: Option<MiniSeries>,
+    pub rated_tier: Option<RatedTier>,
+    pub rated_rating: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TftLeagueList {
+    pub league_id: String,
+    pub tier: Tier,
+    pub queue: TftQueue,
+    pub name: String,
+    pub entries: Vec<LeagueItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RatedLadderEntry {
+    pub puuid: Puuid,
+    pub rated_tier: RatedTier,
+    pub rated_rating: u32,
+    pub wins: u32,
+    pub previous_update_ladder_position: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct TftLeagueV1<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl TftLeagueV1<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).tft_league_v1(platform)
+    }
+}
+
+impl<C: Executor> TftLeagueV1<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn entries_by_puuid(&self, puuid: &Puuid) -> C::Output<Vec<TftLeagueEntry>> {
+        let path = format!("{}/by-puuid/{}", ENDPOINT, puuid);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-league-v1.getLeagueEntriesByPUUID",
+            path,
+        ))
+    }
+
+    pub fn entries_by_summoner(&self, summoner_id: &SummonerId) -> C::Output<Vec<TftLeagueEntry>> {
+        let path = format!("{}/entries/by-summoner/{}", ENDPOINT, summoner_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-league-v1.getLeagueEntriesForSummoner",
+            path,
+        ))
+    }
+
+    pub fn challenger(&self, queue: TftQueue) -> C::Output<TftLeagueList> {
+        let path = format!("{}/challenger?queue={}", ENDPOINT, queue);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-league-v1.getChallengerLeague",
+            path,
+        ))
+    }
+
+    pub fn grandmaster(&self, queue: TftQueue) -> C::Output<TftLeagueList> {
+        let path = format!("{}/grandmaster?queue={}", ENDPOINT, queue);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-league-v1.getGrandmasterLeague",
+            path,
+        ))
+    }
+
+    pub fn master(&self, queue: TftQueue) -> C::Output<TftLeagueList> {
+        let path = format!("{}/master?queue={}", ENDPOINT, queue);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-league-v1.getMasterLeague",
+            path,
+        ))
+    }
+
+    pub fn by_league_id(&self, league_id: &str) -> C::Output<TftLeagueList> {
+        let path = format!("{}/leagues/{}", ENDPOINT, league_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-league-v1.getLeagueById",
+            path,
+        ))
+    }
+
+    /// Paged like [`LeagueV4::entries`](crate::league::league_v4::LeagueV4::entries).
+    pub fn entries(
+        &self,
+        queue: TftQueue,
+        tier: Tier,
+        division: Division,
+        page: u32,
+    ) -> C::Output<Vec<TftLeagueEntry>> {
+        let path = format!(
+            "{}/entries/{}/{}?queue={}&page={}",
+            ENDPOINT, tier, division, queue, page
+        );
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-league-v1.getLeagueEntries",
+            path,
+        ))
+    }
+
+    /// The top of a rated ladder, e.g. [`TftQueue::Turbo`] for Hyper Roll.
+    pub fn rated_ladder(&self, queue: TftQueue) -> C::Output<Vec<RatedLadderEntry>> {
+        let path = format!("{}/rated-ladders/{}/top", ENDPOINT, queue);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-league-v1.getTopRatedLadder",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder, PUUID, SUMMONER_ID};
+
+    #[test]
+    fn test_requests() {
+        let api = TftLeagueV1::with_client(Recorder, Platform::NA1);
+        testing::assert_requests(&[
+            (
+                api.entries_by_puuid(&testing::puuid()),
+                testing::get(
+                    Platform::NA1,
+                    "tft-league-v1.getLeagueEntriesByPUUID",
+                    format!("{}/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.entries_by_summoner(&SUMMONER_ID.parse().unwrap()),
+                testing::get(
+                    Platform::NA1,
+                    "tft-league-v1.getLeagueEntriesForSummoner",
+                    format!("{}/entries/by-summoner/{}", ENDPOINT, SUMMONER_ID),
+                ),
+            ),
+            (
+                api.challenger(TftQueue::Ranked),
+                testing::get(
+                    Platform::NA1,
+                    "tft-league-v1.getChallengerLeague",
+                    format!("{}/challenger?queue=RANKED_TFT", ENDPOINT),
+                ),
+            ),
+            (
+                api.grandmaster(TftQueue::DoubleUp),
+                testing::get(
+                    Platform::NA1,
+                    "tft-league-v1.getGrandmasterLeague",
+                    format!("{}/grandmaster?queue=RANKED_TFT_DOUBLE_UP", ENDPOINT),
+                ),
+            ),
+            (
+                api.master(TftQueue::Other("RANKED_TFT_PAIRS".to_string())),
+                testing::get(
+                    Platform::NA1,
+                    "tft-league-v1.getMasterLeague",
+                    format!("{}/master?queue=RANKED_TFT_PAIRS", ENDPOINT),
+                ),
+            ),
+            (
+                api.by_league_id("league-1"),
+                testing::get(
+                    Platform::NA1,
+                    "tft-league-v1.getLeagueById",
+                    format!("{}/leagues/league-1", ENDPOINT),
+                ),
+            ),
+            (
+                api.entries(TftQueue::DoubleUp, Tier::Gold, Division::II, 2),
+                testing::get(
+                    Platform::NA1,
+                    "tft-league-v1.getLeagueEntries",
+                    format!(
+                        "{}/entries/GOLD/II?queue=RANKED_TFT_DOUBLE_UP&page=2",
+                        ENDPOINT
+                    ),
+                ),
+            ),
+            (
+                api.rated_ladder(TftQueue::Turbo),
+                testing::get(
+                    Platform::NA1,
+                    "tft-league-v1.getTopRatedLadder",
+                    format!("{}/rated-ladders/RANKED_TFT_TURBO/top", ENDPOINT),
+                ),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn test_decode_entries() {
+        let res: Vec<TftLeagueEntry> = testing::decode("tft_league_v1/entries.json");
+        assert_eq!(res.len(), 3);
+        assert_eq!(res[0].queue_type, TftQueue::Ranked);
+        assert_eq!(
+            (res[0].tier, res[0].rank),
+            (Some(Tier::Diamond), Some(Division::III))
+        );
+        assert_eq!(res[1].queue_type, TftQueue::DoubleUp);
+        assert!(res[1].hot_streak);
+
+        let turbo = &res[2];
+        assert_eq!(turbo.queue_type, TftQueue::Turbo);
+        assert_eq!(turbo.tier, None);
+        assert_eq!(turbo.league_points, None);
+        assert_eq!(turbo.rated_tier, Some(RatedTier::Blue));
+        assert_eq!(turbo.rated_rating, Some(2471));
+    }
+
+    #[test]
+    fn test_decode_league() {
+        let res: TftLeagueList = testing::decode("tft_league_v1/challenger.json");
+        assert_eq!(res.queue, TftQueue::Ranked);
+        assert_eq!(res.entries[0].league_points, 1387);
+    }
+
+    #[test]
+    fn test_decode_rated_ladder() {
+        let res: Vec<RatedLadderEntry> = testing::decode("tft_league_v1/rated_ladder.json");
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].rated_tier, RatedTier::Orange);
+        assert_eq!(res[1].previous_update_ladder_position, 1);
+        assert!(RatedTier::Orange > RatedTier::Purple);
+    }
+
+    #[test]
+    fn test_unknown_queue() {
+        let queue: TftQueue = serde_json::from_str(r#""RANKED_TFT_PAIRS""#).unwrap();
+        assert_eq!(queue, TftQueue::Other("RANKED_TFT_PAIRS".to_string()));
+        assert_eq!(
+            serde_json::to_string(&queue).unwrap(),
+            r#""RANKED_TFT_PAIRS""#
+        );
+    }
+}
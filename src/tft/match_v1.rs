@@ -0,0 +1,250 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{query_string, ApiRequest, Executor},
+    ids::{MatchId, MatchIds, Puuid, RiotId},
+    Route,
+};
+pub use crate::{error::Error, Region};
+
+const ENDPOINT: &str = "/tft/match/v1/matches";
+
+// Unlike LoL matches, TFT match data mostly uses snake_case keys.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TftMatch {
+    pub metadata: Metadata,
+    pub info: Info,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    pub data_version: String,
+    pub match_id: MatchId,
+    pub participants: Vec<Puuid>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Info {
+    /// Milliseconds since the Unix epoch.
+    pub game_datetime: i64,
+    /// Seconds.
+    pub game_length: f64,
+    pub game_version: String,
+    pub queue_id: i64,
+    pub tft_game_type: Option<String>,
+    pub tft_set_core_name: Option<String>,
+    pub tft_set_number: i64,
+    pub participants: Vec<Participant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Participant {
+    pub puuid: Puuid,
+    /// 1 to 8, or 1 to 4 in Double Up.
+    pub placement: i64,
+    pub level: i64,
+    pub gold_left: i64,
+    pub last_round: i64,
+    pub players_eliminated: i64,
+    /// Seconds into the game.
+    pub time_eliminated: f64,
+    pub total_damage_to_players: i64,
+    #[serde(default)]
+    pub augments: Vec<String>,
+    pub traits: Vec<Trait>,
+    pub units: Vec<Unit>,
+    pub companion: Companion,
+    /// The player's Double Up team.
+    pub partner_group_id: Option<i64>,
+    #[serde(rename = "riotIdGameName")]
+    pub riot_id_game_name: Option<String>,
+    #[serde(rename = "riotIdTagline")]
+    pub riot_id_tagline: Option<String>,
+}
+
+impl Participant {
+    pub fn riot_id(&self) -> Option<RiotId> {
+        Some(RiotId::new(
+            self.riot_id_game_name.as_ref()?,
+            self.riot_id_tagline.as_ref()?,
+        ))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trait {
+    pub name: String,
+    pub num_units: i64,
+    /// How the trait is drawn, from 0 for inactive to 4 for chromatic.
+    pub style: i64,
+    pub tier_current: i64,
+    pub tier_total: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Unit {
+    pub character_id: String,
+    #[serde(rename = "itemNames", default)]
+    pub item_names: Vec<String>,
+    pub name: String,
+    pub rarity: i64,
+    /// Star level.
+    pub tier: i64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Companion {
+    #[serde(rename = "content_ID")]
+    pub content_id: String,
+    #[serde(rename = "item_ID")]
+    pub item_id: i64,
+    #[serde(rename = "skin_ID")]
+    pub skin_id: i64,
+    pub species: String,
+}
+
+/// The filters TFT accepts, a subset of LoL's
+/// [`ByPUUIDOptions`](crate::league::match_v5::ByPUUIDOptions).
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct TftByPUUIDOptions {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub start: Option<u32>,
+    pub count: Option<u8>,
+}
+
+impl TftByPUUIDOptions {
+    fn query(&self) -> String {
+        query_string(&[
+            ("start", self.start.map(|v| v.to_string())),
+            ("count", self.count.map(|v| v.to_string())),
+            ("startTime", self.start_time.map(|v| v.to_string())),
+            ("endTime", self.end_time.map(|v| v.to_string())),
+        ])
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TftMatchV1<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl TftMatchV1<RiotClient> {
+    pub fn new(key: &str, region: impl Into<Region>) -> Self {
+        RiotClient::new(key).tft_match_v1(region)
+    }
+}
+
+impl<C: Executor> TftMatchV1<C> {
+    pub(crate) fn with_client(client: C, region: impl Into<Region>) -> Self {
+        Self {
+            client,
+            route: Route::Region(region.into()),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn by_puuid(&self, puuid: &Puuid, options: TftByPUUIDOptions) -> C::Output<MatchIds> {
+        let path = format!("{}/by-puuid/{}/ids{}", ENDPOINT, puuid, options.query());
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-match-v1.getMatchIdsByPUUID",
+            path,
+        ))
+    }
+
+    /// Routed by [`MatchId::region`].
+    pub fn match_info(&self, match_id: &MatchId) -> C::Output<TftMatch> {
+        let path = format!("{}/{}", ENDPOINT, match_id);
+        let route = match_id.region().into();
+        self.client
+            .execute(ApiRequest::new(route, "tft-match-v1.getMatch", path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{self, Recorder, MATCH_ID, PUUID},
+        Platform,
+    };
+
+    #[test]
+    fn test_requests() {
+        let api = TftMatchV1::with_client(Recorder, Platform::NA1);
+        let options = TftByPUUIDOptions {
+            count: Some(3),
+            start_time: Some(1698105600),
+            ..Default::default()
+        };
+        testing::assert_requests(&[
+            (
+                api.by_puuid(&testing::puuid(), options),
+                testing::get(
+                    Region::AMERICAS,
+                    "tft-match-v1.getMatchIdsByPUUID",
+                    format!(
+                        "{}/by-puuid/{}/ids?count=3&startTime=1698105600",
+                        ENDPOINT, PUUID
+                    ),
+                ),
+            ),
+            (
+                api.match_info(&MATCH_ID.parse().unwrap()),
+                testing::get(
+                    Region::AMERICAS,
+                    "tft-match-v1.getMatch",
+                    format!("{}/{}", ENDPOINT, MATCH_ID),
+                ),
+            ),
+        ]);
+        let id = MatchId::new(Platform::KR, 1);
+        assert_eq!(api.match_info(&id).route, Route::Region(Region::ASIA));
+    }
+
+    #[test]
+    fn test_decode_match_ids() {
+        let res: MatchIds = testing::decode("tft_match_v1/match_ids.json");
+        assert_eq!(res.len(), 3);
+        assert_eq!(res.ids[0].to_string(), MATCH_ID);
+    }
+
+    #[test]
+    fn test_decode_match() {
+        let res: TftMatch = testing::decode("tft_match_v1/match.json");
+        assert_eq!(res.metadata.match_id.to_string(), MATCH_ID);
+        assert_eq!(res.info.tft_set_number, 9);
+
+        let winner = &res.info.participants[0];
+        assert_eq!(winner.puuid, PUUID);
+        assert_eq!(winner.placement, 1);
+        assert_eq!(winner.augments.len(), 3);
+        assert_eq!(winner.traits[0].name, "Set9_Ionia");
+        assert_eq!(winner.units[0].item_names.len(), 3);
+        assert_eq!(winner.companion.species, "PetDowsie");
+        assert_eq!(
+            winner.riot_id(),
+            Some(RiotId::new("Fixture Summoner", "NA1"))
+        );
+        assert_eq!(res.info.participants[1].riot_id(), None);
+    }
+
+    #[test]
+    fn test_options_query() {
+        assert_eq!(TftByPUUIDOptions::default().query(), "");
+        let options = TftByPUUIDOptions {
+            start: Some(20),
+            end_time: Some(1698192000),
+            ..Default::default()
+        };
+        assert_eq!(options.query(), "?start=20&endTime=1698192000");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod league_v1;
+pub mod match_v1;
+pub mod summoner_v1;
@@ -0,0 +1,101 @@
+#[cfg(feature = "blocking")]
+use crate::RiotClient;
+use crate::{
+    client::{ApiRequest, Executor},
+    ids::{AccountId, Puuid, SummonerId},
+    Route,
+};
+pub use crate::{error::Error, league::summoner_v4::Response, Platform};
+
+const ENDPOINT: &str = "/tft/summoner/v1/summoners";
+
+/// TFT's view of summoners, returning the same [`Response`] as `SummonerV4`.
+#[derive(Debug, Clone)]
+pub struct TftSummonerV1<C> {
+    client: C,
+    route: Route,
+}
+
+#[cfg(feature = "blocking")]
+impl TftSummonerV1<RiotClient> {
+    pub fn new(key: &str, platform: Platform) -> Self {
+        RiotClient::new(key).tft_summoner_v1(platform)
+    }
+}
+
+impl<C: Executor> TftSummonerV1<C> {
+    pub(crate) fn with_client(client: C, platform: Platform) -> Self {
+        Self {
+            client,
+            route: platform.into(),
+        }
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    pub fn by_account(&self, encrypted_account_id: &AccountId) -> C::Output<Response> {
+        let path = format!("{}/by-account/{}", ENDPOINT, encrypted_account_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-summoner-v1.getByAccountId",
+            path,
+        ))
+    }
+
+    pub fn by_puuid(&self, encrypted_puuid: &Puuid) -> C::Output<Response> {
+        let path = format!("{}/by-puuid/{}", ENDPOINT, encrypted_puuid);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-summoner-v1.getByPUUID",
+            path,
+        ))
+    }
+
+    pub fn by_summoner_id(&self, encrypted_summoner_id: &SummonerId) -> C::Output<Response> {
+        let path = format!("{}/{}", ENDPOINT, encrypted_summoner_id);
+        self.client.execute(ApiRequest::new(
+            self.route,
+            "tft-summoner-v1.getBySummonerId",
+            path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Recorder, ACCOUNT_ID, PUUID, SUMMONER_ID};
+
+    #[test]
+    fn test_requests() {
+        let api = TftSummonerV1::with_client(Recorder, Platform::NA1);
+        testing::assert_requests(&[
+            (
+                api.by_account(&ACCOUNT_ID.parse().unwrap()),
+                testing::get(
+                    Platform::NA1,
+                    "tft-summoner-v1.getByAccountId",
+                    format!("{}/by-account/{}", ENDPOINT, ACCOUNT_ID),
+                ),
+            ),
+            (
+                api.by_puuid(&testing::puuid()),
+                testing::get(
+                    Platform::NA1,
+                    "tft-summoner-v1.getByPUUID",
+                    format!("{}/by-puuid/{}", ENDPOINT, PUUID),
+                ),
+            ),
+            (
+                api.by_summoner_id(&SUMMONER_ID.parse().unwrap()),
+                testing::get(
+                    Platform::NA1,
+                    "tft-summoner-v1.getBySummonerId",
+                    format!("{}/{}", ENDPOINT, SUMMONER_ID),
+                ),
+            ),
+        ]);
+    }
+}
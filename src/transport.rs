@@ -0,0 +1,175 @@
+use std::{fmt::Debug, sync::Arc};
+
+use reqwest::{header::HeaderMap, Method, StatusCode};
+
+#[cfg(feature = "async")]
+use crate::client::BoxFuture;
+use crate::error::BoxError;
+
+/// An HTTP request as handed to a `Transport`, with the API key already set
+/// in its `X-Riot-Token` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// Sends requests for a [`RiotClient`](crate::RiotClient). Rate limiting,
+/// retries and decoding all happen above the transport, so an implementation
+/// only has to move bytes, e.g. to serve canned responses in tests.
+#[cfg(feature = "blocking")]
+pub trait Transport: Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, BoxError>;
+}
+
+/// Async counterpart of `Transport` used by an
+/// [`AsyncRiotClient`](crate::AsyncRiotClient).
+#[cfg(feature = "async")]
+pub trait AsyncTransport: Debug + Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<Result<HttpResponse, BoxError>>;
+}
+
+#[cfg(feature = "blocking")]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, BoxError> {
+        (**self).send(request)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: AsyncTransport + ?Sized> AsyncTransport for Arc<T> {
+    fn send(&self, request: HttpRequest) -> BoxFuture<Result<HttpResponse, BoxError>> {
+        (**self).send(request)
+    }
+}
+
+/// The default transport, backed by a `reqwest` blocking client.
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+}
+
+#[cfg(feature = "blocking")]
+impl ReqwestTransport {
+    pub fn new(client: reqwest::blocking::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse, BoxError> {
+        let mut builder = self
+            .client
+            .request(request.method, &request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        let response = builder.send()?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        Ok(HttpResponse {
+            status,
+            headers,
+            body: response.bytes()?.to_vec(),
+        })
+    }
+}
+
+/// The default async transport, backed by a `reqwest` client.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone, Default)]
+pub struct AsyncReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl AsyncReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for AsyncReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<Result<HttpResponse, BoxError>> {
+        let mut builder = self
+            .client
+            .request(request.method, &request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        Box::pin(async move {
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            Ok(HttpResponse {
+                status,
+                headers,
+                body: response.bytes().await?.to_vec(),
+            })
+        })
+    }
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{testing, Platform, RiotClient};
+
+    #[derive(Debug, Default)]
+    struct StaticTransport {
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    impl Transport for StaticTransport {
+        fn send(&self, request: HttpRequest) -> Result<HttpResponse, BoxError> {
+            self.requests.lock().unwrap().push(request);
+            Ok(HttpResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: crate::testing::fixture("summoner_v4/summoner.json").into_bytes(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_custom_transport() {
+        let transport = Arc::new(StaticTransport::default());
+        let client = RiotClient::builder("key")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        let res = client
+            .summoner_v4(Platform::EUW1)
+            .by_puuid(&testing::puuid())
+            .unwrap();
+        assert_eq!(res.name, "Fixture Summoner");
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(
+            requests[0].url,
+            format!(
+                "https://euw1.api.riotgames.com/lol/summoner/v4/summoners/by-puuid/{}",
+                testing::PUUID
+            )
+        );
+        assert_eq!(requests[0].headers["X-Riot-Token"], "key");
+    }
+}